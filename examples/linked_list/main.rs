@@ -1,12 +1,6 @@
-extern crate ghost_cell;
 extern crate ghost_sea;
-extern crate static_rc;
 
-mod linked_list;
-mod ghost_linked_list;
-
-use self::ghost_linked_list::GhostLinkedList;
-use self::linked_list::LinkedList;
+use ghost_sea::collections::LinkedList;
 
 fn main() {
     let mut list: LinkedList<String> = LinkedList::default();
@@ -27,7 +21,7 @@ fn main() {
     assert_eq!(Some("Hello, World!"), list.front().map(|s| &**s));
     assert_eq!(Some("Hello, You!"), list.back().map(|s| &**s));
 
-    //println!("{:?}", list);
+    println!("{:?}", list);
 
     list.clear();
 }
@@ -0,0 +1,365 @@
+//! Derive macro for `ghost_sea::GhostProject`.
+//!
+//! This crate is not meant to be used directly; instead, enable the `derive` feature of `ghost-sea` and use the
+//! re-exported `ghost_sea::GhostProject` derive.
+
+//  Lints.
+#![deny(missing_docs)]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote,
+    visit::{self, Visit},
+    visit_mut::{self, VisitMut},
+    Attribute, Data, DeriveInput, Error, Fields, GenericArgument, GenericParam, Generics, Lifetime, LifetimeParam,
+    PathArguments, PathSegment, Result, Type, TypeMacro, WherePredicate,
+};
+
+/// Derives `GhostProject`, and its reverse `GhostUnproject`, for a struct or enum.
+///
+/// The brand lifetime is designated with the `#[ghost(brand = 'brand)]` attribute, and the derive generates:
+///
+/// ```ignore
+/// unsafe impl<'id> GhostProject<'id> for Type<'static> {
+///     type Branded = Type<'id>;
+/// }
+///
+/// impl<'id> GhostUnproject<'id> for Type<'id> {
+///     type Unbranded = Type<'static>;
+/// }
+/// ```
+///
+/// Without the attribute, the type is considered brand-free and projects onto itself.
+///
+/// #   Verification
+///
+/// The derive refuses to compile when:
+///
+/// -   The type has a lifetime parameter other than the brand.
+/// -   A field mentions `GhostToken`.
+/// -   A field uses a lifetime other than the brand in brand position, such as `GhostCell<'static, T>`.
+/// -   A field does not implement `GhostProject` with a matching `Branded` type; that is
+///     `Field<'static>: GhostProject<'id, Branded = Field<'id>>` must hold.
+/// -   A field mentioning the brand is not known to be free of `GhostToken`: it must be built from the types of this
+///     crate, the standard types it supports, and types deriving `GhostProject` or declared with `ghost_members!`.
+///
+/// In particular, fields which do not mention the brand must be proven brand-free, projecting onto themselves. This
+/// includes generic parameters, whether they appear as a field or within one, as in `GhostCell<'brand, T>`, which
+/// require a `T: for<'x> GhostProject<'x, Branded = T>` bound.
+///
+/// The syntactic checks cannot see through type aliases, but the trait checks do: a `GhostCell<'static, T>` or a
+/// `GhostToken<'static>` hidden behind an alias does not project onto itself, and a `GhostToken<'brand>` hidden behind
+/// an alias taking the brand as a parameter is not known to be free of tokens, hence all are rejected.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostProject};
+/// use ghost_sea::collections::GhostLinkedList;
+///
+/// #[derive(GhostProject)]
+/// #[ghost(brand = 'brand)]
+/// struct Graph<'brand, T>
+/// where
+///     T: for<'x> GhostProject<'x, Branded = T>,
+/// {
+///     name: T,
+///     nodes: GhostLinkedList<'brand, Data>,
+///     count: GhostCell<'brand, usize>,
+/// }
+///
+/// #[derive(GhostProject)]
+/// struct Data(u32, String);
+/// # fn main() {}
+/// ```
+///
+/// A `GhostToken` cannot be embedded:
+///
+/// ```compile_fail
+/// use ghost_sea::{GhostProject, GhostToken};
+///
+/// #[derive(GhostProject)]
+/// #[ghost(brand = 'brand)]
+/// struct Smuggler<'brand>(GhostToken<'brand>);
+/// # fn main() {}
+/// ```
+///
+/// Nor can another lifetime be used as a brand:
+///
+/// ```compile_fail
+/// use ghost_sea::{GhostCell, GhostProject};
+///
+/// #[derive(GhostProject)]
+/// #[ghost(brand = 'brand)]
+/// struct Mixed<'brand>(GhostCell<'brand, u32>, GhostCell<'static, u32>);
+/// # fn main() {}
+/// ```
+///
+/// And the brand must only appear in brand position:
+///
+/// ```compile_fail,E0277
+/// use ghost_sea::GhostProject;
+///
+/// #[derive(GhostProject)]
+/// #[ghost(brand = 'brand)]
+/// struct Borrowed<'brand>(&'brand str);
+/// # fn main() {}
+/// ```
+///
+/// Nor can a field be assumed brand-free:
+///
+/// ```compile_fail,E0277
+/// use ghost_sea::{GhostCell, GhostProject};
+///
+/// #[derive(GhostProject)]
+/// #[ghost(brand = 'brand)]
+/// struct Generic<'brand, T>(GhostCell<'brand, u32>, T);
+/// # fn main() {}
+/// ```
+#[proc_macro_derive(GhostProject, attributes(ghost))]
+pub fn derive_ghost_project(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+//
+//  Implementation
+//
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let brand = parse_brand(&input.attrs)?;
+
+    check_generics(&input.generics, brand.as_ref())?;
+
+    let fields = collect_fields(&input)?;
+
+    for field in &fields {
+        check_field(field, brand.as_ref())?;
+    }
+
+    let id = Lifetime::new("'__ghost_id", Span::call_site());
+    let static_ = Lifetime::new("'static", Span::call_site());
+
+    let name = &input.ident;
+
+    //  Generics of the implementation: `'id`, followed by all but the brand, with the brand replaced by `'static`.
+    let mut generics = input.generics.clone();
+    generics.params = generics.params.into_iter()
+        .filter(|param| !matches!(param, GenericParam::Lifetime(l) if Some(&l.lifetime) == brand.as_ref()))
+        .collect();
+    generics.params.insert(0, GenericParam::Lifetime(LifetimeParam::new(id.clone())));
+
+    if let Some(brand) = &brand {
+        Rebrand { from: brand, to: &static_ }.visit_generics_mut(&mut generics);
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let static_args = type_arguments(&input.generics, brand.as_ref(), &static_);
+    let branded_args = type_arguments(&input.generics, brand.as_ref(), &id);
+
+    //  Every field is checked, those not mentioning the brand being required to project onto themselves, which a token
+    //  does not, and those mentioning the brand being required to project and contain no token.
+    let assertions = fields.iter()
+        .map(|field| match &brand {
+            Some(brand) if mentions(field, brand) => {
+                let (unbranded, branded) = (rebrand(field, brand, &static_), rebrand(field, brand, &id));
+
+                quote! { ::ghost_sea::__private::assert_project::<#id, #unbranded, #branded>(); }
+            },
+            _ => quote! { ::ghost_sea::__private::assert_brand_free::<#id, #field>(); },
+        });
+
+    //  Every type parameter is checked to project onto itself, and may then be assumed to contain no token.
+    let parameters: Vec<_> = input.generics.type_params().map(|param| &param.ident).collect();
+
+    let mut check_generics = generics.clone();
+    check_generics.make_where_clause().predicates.extend(parameters.iter().map(|parameter| -> WherePredicate {
+        parse_quote! { #parameter: ::ghost_sea::__private::ProjectField<#id> }
+    }));
+
+    let (check_generics, _, check_where_clause) = check_generics.split_for_impl();
+
+    Ok(quote! {
+        //  Safety:
+        //  -   `'static` is the brand, and only the brand, as verified below.
+        unsafe impl #impl_generics ::ghost_sea::GhostProject<#id> for #name #static_args #where_clause {
+            type Branded = #name #branded_args;
+        }
+
+        impl #impl_generics ::ghost_sea::GhostUnproject<#id> for #name #branded_args #where_clause {
+            type Unbranded = #name #static_args;
+        }
+
+        //  Safety:
+        //  -   No field contains a token, as verified below.
+        unsafe impl #impl_generics ::ghost_sea::__private::ProjectField<#id> for #name #static_args #where_clause {}
+
+        const _: () = {
+            #[allow(dead_code)]
+            fn __ghost_project_check #check_generics () #check_where_clause {
+                #(::ghost_sea::__private::assert_brand_free::<#id, #parameters>();)*
+                #(#assertions)*
+            }
+        };
+    })
+}
+
+//  Parses `#[ghost(brand = 'brand)]`, if any.
+fn parse_brand(attrs: &[Attribute]) -> Result<Option<Lifetime>> {
+    let mut brand = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("ghost")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("brand") {
+                return Err(meta.error("unknown ghost attribute, expected `brand`"));
+            }
+
+            if brand.is_some() {
+                return Err(meta.error("duplicate brand"));
+            }
+
+            brand = Some(meta.value()?.parse::<Lifetime>()?);
+
+            Ok(())
+        })?;
+    }
+
+    Ok(brand)
+}
+
+//  Checks that the brand, if any, is the one and only lifetime parameter.
+fn check_generics(generics: &Generics, brand: Option<&Lifetime>) -> Result<()> {
+    let mut found = false;
+
+    for lifetime in generics.lifetimes() {
+        match brand {
+            Some(brand) if lifetime.lifetime == *brand => found = true,
+            Some(_) => return Err(Error::new_spanned(lifetime, "the brand must be the only lifetime parameter")),
+            None => return Err(Error::new_spanned(lifetime, "lifetime parameters require `#[ghost(brand = '...)]`")),
+        }
+    }
+
+    match brand {
+        Some(brand) if !found => Err(Error::new_spanned(brand, "the brand is not a lifetime parameter of the type")),
+        _ => Ok(()),
+    }
+}
+
+//  Collects the types of all fields, of all variants.
+fn collect_fields(input: &DeriveInput) -> Result<Vec<Type>> {
+    fn of(fields: &Fields) -> impl Iterator<Item = Type> + '_ { fields.iter().map(|field| field.ty.clone()) }
+
+    match &input.data {
+        Data::Struct(data) => Ok(of(&data.fields).collect()),
+        Data::Enum(data) => Ok(data.variants.iter().flat_map(|variant| of(&variant.fields)).collect()),
+        Data::Union(_) => Err(Error::new_spanned(&input.ident, "GhostProject cannot be derived for unions")),
+    }
+}
+
+//  Checks that the field contains no token, and no other lifetime in brand position.
+fn check_field(field: &Type, brand: Option<&Lifetime>) -> Result<()> {
+    let mut checker = FieldChecker { brand, error: None };
+
+    checker.visit_type(field);
+
+    checker.error.map_or(Ok(()), Err)
+}
+
+//  Returns whether `field` mentions `brand`.
+fn mentions(field: &Type, brand: &Lifetime) -> bool {
+    let mut finder = BrandFinder { brand, found: false };
+
+    finder.visit_type(field);
+
+    finder.found
+}
+
+//  Returns the type arguments of the type, with the brand replaced by `lifetime`.
+fn type_arguments(generics: &Generics, brand: Option<&Lifetime>, lifetime: &Lifetime) -> TokenStream2 {
+    let arguments = generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(l) if Some(&l.lifetime) == brand => quote! { #lifetime },
+        GenericParam::Lifetime(l) => { let l = &l.lifetime; quote! { #l } },
+        GenericParam::Type(t) => { let t = &t.ident; quote! { #t } },
+        GenericParam::Const(c) => { let c = &c.ident; quote! { #c } },
+    });
+
+    quote! { < #(#arguments),* > }
+}
+
+//  Returns a copy of `field` with `brand` replaced by `lifetime`.
+fn rebrand(field: &Type, brand: &Lifetime, lifetime: &Lifetime) -> Type {
+    let mut field = field.clone();
+    Rebrand { from: brand, to: lifetime }.visit_type_mut(&mut field);
+
+    field
+}
+
+struct FieldChecker<'a> {
+    brand: Option<&'a Lifetime>,
+    error: Option<Error>,
+}
+
+impl<'a> FieldChecker<'a> {
+    fn report(&mut self, error: Error) {
+        match &mut self.error {
+            Some(existing) => existing.combine(error),
+            None => self.error = Some(error),
+        }
+    }
+}
+
+impl<'a, 'ast> Visit<'ast> for FieldChecker<'a> {
+    fn visit_path_segment(&mut self, segment: &'ast PathSegment) {
+        if segment.ident == "GhostToken" {
+            self.report(Error::new_spanned(segment, "GhostProject cannot be derived for types containing a GhostToken"));
+        }
+
+        if segment.ident == "GhostCell" {
+            if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                if let Some(GenericArgument::Lifetime(lifetime)) = arguments.args.first() {
+                    if Some(lifetime) != self.brand {
+                        self.report(Error::new_spanned(lifetime, "only the brand may be used in brand position"));
+                    }
+                }
+            }
+        }
+
+        visit::visit_path_segment(self, segment);
+    }
+
+    fn visit_type_macro(&mut self, mac: &'ast TypeMacro) {
+        self.report(Error::new_spanned(mac, "GhostProject cannot verify macro types"));
+    }
+}
+
+struct BrandFinder<'a> {
+    brand: &'a Lifetime,
+    found: bool,
+}
+
+impl<'a, 'ast> Visit<'ast> for BrandFinder<'a> {
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        self.found |= lifetime == self.brand;
+    }
+}
+
+struct Rebrand<'a> {
+    from: &'a Lifetime,
+    to: &'a Lifetime,
+}
+
+impl<'a> VisitMut for Rebrand<'a> {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime == self.from {
+            *lifetime = self.to.clone();
+        }
+
+        visit_mut::visit_lifetime_mut(self, lifetime);
+    }
+}
@@ -0,0 +1,8 @@
+//  Verifies that the derive rejects types which cannot be projected soundly.
+
+#[test]
+fn compile_fail() {
+    let tests = trybuild::TestCases::new();
+
+    tests.compile_fail("tests/ui/*.rs");
+}
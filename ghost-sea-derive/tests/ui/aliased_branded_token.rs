@@ -0,0 +1,11 @@
+//  An alias taking the brand as a parameter may hide a `GhostToken`, out of reach of the syntactic checks.
+
+use ghost_sea::{GhostCell, GhostProject, GhostToken};
+
+type Token<'b> = GhostToken<'b>;
+
+#[derive(GhostProject)]
+#[ghost(brand = 'brand)]
+struct Smuggler<'brand>(GhostCell<'brand, u32>, Token<'brand>);
+
+fn main() {}
@@ -0,0 +1,14 @@
+//  An alias may hide a `GhostCell<'static, T>`, out of reach of the syntactic checks.
+
+use ghost_sea::{GhostCell, GhostProject};
+
+type Static = GhostCell<'static, u32>;
+
+#[derive(GhostProject)]
+#[ghost(brand = 'brand)]
+struct Aliased<'brand> {
+    cell: GhostCell<'brand, u32>,
+    hidden: Static,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+//  An alias may hide a `GhostToken`, out of reach of the syntactic checks.
+
+use ghost_sea::{GhostProject, GhostToken};
+
+type Token = GhostToken<'static>;
+
+#[derive(GhostProject)]
+struct Smuggler(Token);
+
+fn main() {}
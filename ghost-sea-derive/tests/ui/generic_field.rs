@@ -0,0 +1,12 @@
+//  A generic field may hide a `GhostCell<'static, T>`, hence must be proven brand-free.
+
+use ghost_sea::{GhostCell, GhostProject};
+
+#[derive(GhostProject)]
+#[ghost(brand = 'brand)]
+struct Generic<'brand, T> {
+    cell: GhostCell<'brand, u32>,
+    value: T,
+}
+
+fn main() {}
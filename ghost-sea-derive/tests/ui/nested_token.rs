@@ -0,0 +1,11 @@
+//  A `GhostToken` may be nested within a container, out of reach of the syntactic checks when aliased.
+
+use ghost_sea::{GhostCell, GhostProject, GhostToken};
+
+type Token<'b> = GhostToken<'b>;
+
+#[derive(GhostProject)]
+#[ghost(brand = 'brand)]
+struct Smuggler<'brand>(GhostCell<'brand, u32>, Option<(Token<'brand>,)>);
+
+fn main() {}
@@ -0,0 +1,9 @@
+//  A `GhostToken` cannot be embedded.
+
+use ghost_sea::{GhostProject, GhostToken};
+
+#[derive(GhostProject)]
+#[ghost(brand = 'brand)]
+struct Smuggler<'brand>(GhostToken<'brand>);
+
+fn main() {}
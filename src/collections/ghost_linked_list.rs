@@ -0,0 +1,2619 @@
+//  A GhostLinkedList, with externally supplied token.
+//
+//  A number of operations normally implemented by traits cannot be successfully implemented on this collection due to
+//  the requirement of supplying the GhostToken externally; token-aware equivalents, such as `GhostDebug`, are
+//  implemented instead.
+//
+//  While this may seem like a harsh requirement, it provides some flexibility; see `LinkedList` for a stand-alone
+//  version.
+//
+//  #   Safety
+//
+//  There's little `unsafe` code: the implementation of `GhostProject`, the extension of lifetimes in the mutable
+//  iterator, which cannot otherwise hand out multiple mutable references, and the unbinding of references to nodes in
+//  the mutable cursor, when relinking, and when using handles, which cannot otherwise refer to a node whose thirds are
+//  being moved. Similarly, `dedup_by` borrows two distinct nodes mutably at once, which `GhostCell` cannot express,
+//  and `ghost_clone` links the nodes of the clone without the mutable token it does not have.
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::Hasher,
+    iter::FusedIterator,
+    mem::{self, ManuallyDrop},
+    ptr::{self, NonNull},
+    sync::atomic::{self, AtomicUsize},
+};
+
+use ghost_cell::{GhostCell, GhostToken};
+use static_rc::StaticRc;
+
+use crate::{
+    __private::ProjectField, ghost_cmp, GhostClone, GhostCloneContext, GhostDebug, GhostDrop, GhostEq, GhostHash,
+    GhostOrd, GhostPartialEq, GhostPartialOrd, GhostProject, GhostUnproject, WithToken,
+};
+
+#[cfg(feature = "serde")]
+use serde::Serializer;
+
+#[cfg(feature = "serde")]
+use crate::GhostSerialize;
+
+/// A safe implementation of a linked-list build upon `GhostCell` and `StaticRc`.
+///
+/// The future is now!
+///
+/// Since the nodes can only be unlinked with the token, the list cannot be drained on drop: a non-empty list should be
+/// cleared before being dropped, lest its nodes be leaked -- or a panic be raised, in Debug. Within a `GhostDropSea`,
+/// the list is cleared automatically.
+///
+/// An element may be pushed, or inserted by a cursor, alongside a `NodeHandle` designating it, for O(1) access, removal
+/// and moving via `get`, `remove` and `move_to_front`. To this end, each node holds a pointer to itself and the
+/// identifier of its list, on top of its element and the links to its neighbours, whether or not handles are used.
+pub struct GhostLinkedList<'brand, T> {
+    //  Invariant: either both are `Some`, or both are `None`.
+    head: Option<ThirdNodePtr<'brand, T>>,
+    tail: Option<ThirdNodePtr<'brand, T>>,
+    len: usize,
+    //  Invariant: either 0, and no node is designated by a handle, or distinct from the identifier of any other list,
+    //  and the `owner` of every node designated by a handle.
+    id: usize,
+}
+
+impl<'brand, T> GhostLinkedList<'brand, T> {
+    /// Creates an instance.
+    pub fn new() -> Self { Self { head: None, tail: None, len: 0, id: 0, } }
+
+    /// Creates an iterator over self.
+    pub fn iter<'a>(&'a self, token: &'a GhostToken<'brand>) -> GhostLinkedListIterator<'a, 'brand, T> {
+        let len = self.len;
+        let head_tail = self.head.as_deref().zip(self.tail.as_deref());
+
+        GhostLinkedListIterator { token, head_tail, len, }
+    }
+
+    /// Creates a mutable iterator over self.
+    pub fn iter_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>)
+        -> GhostLinkedListIteratorMut<'a, 'brand, T>
+    {
+        let len = self.len;
+        let head_tail = self.head.as_deref().zip(self.tail.as_deref());
+
+        GhostLinkedListIteratorMut { token, head_tail, len, }
+    }
+
+    /// Creates a cursor pointing to the front element, or the "ghost" element if empty.
+    pub fn cursor_front<'a>(&'a self, token: &'a GhostToken<'brand>) -> GhostLinkedListCursor<'a, 'brand, T> {
+        GhostLinkedListCursor { token, list: self, current: self.head.as_deref(), index: 0, }
+    }
+
+    /// Creates a cursor pointing to the back element, or the "ghost" element if empty.
+    pub fn cursor_back<'a>(&'a self, token: &'a GhostToken<'brand>) -> GhostLinkedListCursor<'a, 'brand, T> {
+        GhostLinkedListCursor { token, list: self, current: self.tail.as_deref(), index: self.len.saturating_sub(1), }
+    }
+
+    /// Creates a mutable cursor pointing to the front element, or the "ghost" element if empty.
+    pub fn cursor_front_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>)
+        -> GhostLinkedListCursorMut<'a, 'brand, T>
+    {
+        let current = self.head.as_deref().map(NonNull::from);
+
+        GhostLinkedListCursorMut { token, list: self, current, index: 0, }
+    }
+
+    /// Creates a mutable cursor pointing to the back element, or the "ghost" element if empty.
+    pub fn cursor_back_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>)
+        -> GhostLinkedListCursorMut<'a, 'brand, T>
+    {
+        let current = self.tail.as_deref().map(NonNull::from);
+        let index = self.len.saturating_sub(1);
+
+        GhostLinkedListCursorMut { token, list: self, current, index, }
+    }
+
+    /// Returns whether the list is empty, or not.
+    pub fn is_empty(&self) -> bool { self.head.is_none() }
+
+    /// Returns the length of the list.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1)
+    pub fn len(&self) -> usize { self.len }
+
+    /// Clears the list.
+    ///
+    /// Should the destructor of an element panic, the remaining elements are still dropped.
+    pub fn clear(&mut self, token: &mut GhostToken<'brand>) {
+        struct DropGuard<'a, 'brand, T>(&'a mut GhostLinkedList<'brand, T>, &'a mut GhostToken<'brand>);
+
+        impl<'a, 'brand, T> Drop for DropGuard<'a, 'brand, T> {
+            fn drop(&mut self) { while self.0.pop_front(self.1).is_some() {} }
+        }
+
+        let guard = DropGuard(self, token);
+
+        while let Some(element) = guard.0.pop_front(guard.1) {
+            drop(element);
+        }
+
+        mem::forget(guard);
+    }
+
+    /// Returns the front item, if any.
+    pub fn front<'a>(&'a self, token: &'a GhostToken<'brand>) -> Option<&'a T> {
+        self.head.as_ref().map(|head| head.borrow(token).data())
+    }
+
+    /// Returns the front item, if any.
+    pub fn front_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>) -> Option<&'a mut T> {
+        self.head.as_ref().map(move |head| head.borrow_mut(token).data_mut())
+    }
+
+    /// Returns the back item, if any.
+    pub fn back<'a>(&'a self, token: &'a GhostToken<'brand>) -> Option<&'a T> {
+        self.tail.as_ref().map(|tail| tail.borrow(token).data())
+    }
+
+    /// Returns the back item, if any.
+    pub fn back_mut<'a>(&'a mut self, token: &'a mut GhostToken<'brand>) -> Option<&'a mut T> {
+        self.tail.as_ref().map(move |tail| tail.borrow_mut(token).data_mut())
+    }
+
+    /// Pushes an item at the front of the list.
+    pub fn push_front(&mut self, data: T, token: &mut GhostToken<'brand>) {
+        let thirds = Self::new_node(data, token);
+
+        self.push_front_node(thirds, token);
+    }
+
+    /// Pushes an item at the front of the list, and returns a handle to it.
+    pub fn push_front_with_handle(&mut self, data: T, token: &mut GhostToken<'brand>) -> NodeHandle<'brand, T> {
+        let (thirds, handle) = self.new_designated_node(data);
+
+        self.push_front_node(thirds, token);
+
+        handle
+    }
+
+    fn push_front_node(&mut self, (one, two): ThirdNodePair<'brand, T>, token: &mut GhostToken<'brand>) {
+        self.len += 1;
+
+        if let Some(head) = self.head.take() {
+            head.borrow_mut(token).prev = Some(one);
+
+            two.borrow_mut(token).next = Some(head);
+
+            self.head = Some(two);
+        } else {
+            self.head = Some(one);
+            self.tail = Some(two);
+        }
+    }
+
+    /// Pops the front item of the list, if any.
+    pub fn pop_front(&mut self, token: &mut GhostToken<'brand>) -> Option<T> {
+        let head = self.head.take()?;
+
+        self.len -= 1;
+
+        if self.is_tail(&head) {
+            let tail = self.tail.take().expect("Non-empty list should have a tail");
+
+            return Some(Self::into_inner(head, tail, token));
+        }
+
+        let next = head.borrow_mut(token).next.take()
+            .expect("Non-tail should have a next node");
+        let other_head = next.borrow_mut(token).prev.take()
+            .expect("Non-head should have a previous node");
+
+        self.head = Some(next);
+
+        Some(Self::into_inner(head, other_head, token))
+    }
+
+    /// Pushes an item at the back of the list.
+    pub fn push_back(&mut self, data: T, token: &mut GhostToken<'brand>) {
+        let thirds = Self::new_node(data, token);
+
+        self.push_back_node(thirds, token);
+    }
+
+    /// Pushes an item at the back of the list, and returns a handle to it.
+    pub fn push_back_with_handle(&mut self, data: T, token: &mut GhostToken<'brand>) -> NodeHandle<'brand, T> {
+        let (thirds, handle) = self.new_designated_node(data);
+
+        self.push_back_node(thirds, token);
+
+        handle
+    }
+
+    fn push_back_node(&mut self, (one, two): ThirdNodePair<'brand, T>, token: &mut GhostToken<'brand>) {
+        self.len += 1;
+
+        if let Some(tail) = self.tail.take() {
+            tail.borrow_mut(token).next = Some(one);
+
+            two.borrow_mut(token).prev = Some(tail);
+
+            self.tail = Some(two);
+        } else {
+            self.head = Some(one);
+            self.tail = Some(two);
+        }
+    }
+
+    /// Pops the back item of the list, if any.
+    pub fn pop_back(&mut self, token: &mut GhostToken<'brand>) -> Option<T> {
+        let tail = self.tail.take()?;
+
+        self.len -= 1;
+
+        if self.is_head(&tail) {
+            let head = self.head.take().expect("Non-empty list should have a head");
+
+            return Some(Self::into_inner(tail, head, token));
+        }
+
+        let prev = tail.borrow_mut(token).prev.take()
+            .expect("Non-head should have a previous node");
+        let other_tail = prev.borrow_mut(token).next.take()
+            .expect("Non-tail should have a next node");
+
+        self.tail = Some(prev);
+
+        Some(Self::into_inner(tail, other_tail, token))
+    }
+
+    /// Moves all elements of `other` to the back of the list, leaving `other` empty.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1), or O(min(len, other.len)) if both lists handed out handles, see `NodeHandle`.
+    pub fn append(&mut self, other: &mut Self, token: &mut GhostToken<'brand>) {
+        self.merge_id(other, token);
+
+        if let (Some(first), Some(last)) = (other.head.take(), other.tail.take()) {
+            //  Safety:
+            //  -   The tail is linked in the list, hence alive, for the duration of the call.
+            let tail = self.tail.as_deref().map(|node| unsafe { unbind(node) });
+
+            self.link(tail, None, (first, last), mem::take(&mut other.len), token);
+        }
+    }
+
+    /// Splits the list in two at the given index, returning the elements from `at` onwards.
+    ///
+    /// #   Panics
+    ///
+    /// If `at > len`.
+    ///
+    /// #   Complexity
+    ///
+    /// O(min(at, len - at)), to walk from the closest end.
+    pub fn split_off(&mut self, at: usize, token: &mut GhostToken<'brand>) -> Self {
+        let len = self.len;
+
+        assert!(at <= len, "Cannot split off at a nonexistent index");
+
+        let mut cursor = if at <= len / 2 {
+            let mut cursor = self.cursor_front_mut(token);
+            (0..at).for_each(|_| cursor.move_next());
+            cursor
+        } else {
+            let mut cursor = self.cursor_back_mut(token);
+            cursor.move_next();
+            (at..len).for_each(|_| cursor.move_prev());
+            cursor
+        };
+
+        cursor.split_off()
+    }
+
+    /// Splits the list in two at the current element of `cursor`, returning it and the elements after it.
+    ///
+    /// If the cursor points to the "ghost" element, an empty list is returned. See
+    /// `GhostLinkedListCursorMut::split_off`.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1), or linear in the length of the shorter part if the list handed out handles, see `NodeHandle`.
+    pub fn split_off_cursor(mut cursor: GhostLinkedListCursorMut<'_, 'brand, T>) -> Self { cursor.split_off() }
+
+    /// Sorts the list, preserving the order of equal elements.
+    ///
+    /// The nodes are relinked in place, without allocating.
+    ///
+    /// #   Complexity
+    ///
+    /// O(N log N)
+    pub fn sort(&mut self, token: &mut GhostToken<'brand>)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp, token)
+    }
+
+    /// Sorts the list with a comparator function, preserving the order of equal elements.
+    ///
+    /// The nodes are relinked in place, without allocating. Should `compare` panic, all elements remain in the list, in
+    /// an unspecified order.
+    ///
+    /// #   Complexity
+    ///
+    /// O(N log N)
+    pub fn sort_by<F>(&mut self, mut compare: F, token: &mut GhostToken<'brand>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        //  Gathers back all elements in the list, even if `compare` panics.
+        //
+        //  The elements remain labelled with the identifier of the list, which is set aside meanwhile, so that moving
+        //  them between the lists of the guard does not relabel them.
+        struct Guard<'a, 'brand, T> {
+            list: &'a mut GhostLinkedList<'brand, T>,
+            token: &'a mut GhostToken<'brand>,
+            id: usize,
+            sorted: GhostLinkedList<'brand, T>,
+            left: GhostLinkedList<'brand, T>,
+            right: GhostLinkedList<'brand, T>,
+        }
+
+        impl<'a, 'brand, T> Drop for Guard<'a, 'brand, T> {
+            fn drop(&mut self) {
+                for other in [&mut self.sorted, &mut self.left, &mut self.right] {
+                    self.list.append(other, self.token);
+                }
+
+                self.list.id = self.id;
+            }
+        }
+
+        let len = self.len;
+        let id = mem::take(&mut self.id);
+
+        let mut guard = Guard {
+            list: self,
+            id,
+            token,
+            sorted: GhostLinkedList::default(),
+            left: GhostLinkedList::default(),
+            right: GhostLinkedList::default(),
+        };
+
+        //  Bottom-up merge sort: each pass merges pairs of consecutive sorted runs of `width` elements.
+        let mut width = 1;
+
+        while width < len {
+            while !guard.list.is_empty() {
+                guard.left = guard.list.split_front(width, guard.token);
+                guard.right = guard.list.split_front(width, guard.token);
+
+                Self::merge(&mut guard.sorted, &mut guard.left, &mut guard.right, &mut compare, guard.token);
+            }
+
+            mem::swap(guard.list, &mut guard.sorted);
+
+            width = width.saturating_mul(2);
+        }
+    }
+
+    /// Sorts the list with a key extraction function, preserving the order of equal elements.
+    ///
+    /// The nodes are relinked in place, without allocating.
+    ///
+    /// #   Complexity
+    ///
+    /// O(N log N)
+    pub fn sort_by_key<K, F>(&mut self, mut fun: F, token: &mut GhostToken<'brand>)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| fun(a).cmp(&fun(b)), token)
+    }
+
+    /// Reverses the order of the elements of the list.
+    ///
+    /// #   Complexity
+    ///
+    /// O(N)
+    pub fn reverse(&mut self, token: &mut GhostToken<'brand>) {
+        //  Safety:
+        //  -   The nodes remain linked in the list, hence alive, for the duration of the call.
+        let mut current = self.head.as_deref().map(|node| unsafe { unbind(node) });
+
+        while let Some(node) = current {
+            let node = node.borrow_mut(token);
+
+            mem::swap(&mut node.prev, &mut node.next);
+
+            //  Safety:
+            //  -   As above.
+            current = node.prev.as_deref().map(|node| unsafe { unbind(node) });
+        }
+
+        mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Removes consecutive elements which are equal, keeping the first of each run.
+    ///
+    /// #   Complexity
+    ///
+    /// O(N)
+    pub fn dedup(&mut self, token: &mut GhostToken<'brand>)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b, token)
+    }
+
+    /// Removes consecutive elements which resolve to the same key, keeping the first of each run.
+    ///
+    /// #   Complexity
+    ///
+    /// O(N)
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F, token: &mut GhostToken<'brand>)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.dedup_by(|a, b| key(a) == key(b), token)
+    }
+
+    /// Removes consecutive elements satisfying the given equality relation, keeping the first of each run.
+    ///
+    /// As with `Vec::dedup_by`, `same_bucket(a, b)` is passed the element under consideration as `a`, and the
+    /// preceding element which was kept as `b`.
+    ///
+    /// #   Complexity
+    ///
+    /// O(N)
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F, token: &mut GhostToken<'brand>)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let mut cursor = self.cursor_front_mut(token);
+
+        while let (Some(kept), Some(next)) = (cursor.current_node(), cursor.next_node()) {
+            let kept: *mut T = kept.borrow_mut(cursor.token).data_mut();
+            let next: *mut T = next.borrow_mut(cursor.token).data_mut();
+
+            //  Safety:
+            //  -   `kept` and `next` are distinct nodes, hence the references do not alias.
+            //  -   The token is exclusively borrowed by the cursor, hence no other reference to either exists.
+            let same = unsafe { same_bucket(&mut *next, &mut *kept) };
+
+            cursor.move_next();
+
+            if same {
+                cursor.remove_current();
+                cursor.move_prev();
+            }
+        }
+    }
+
+    /// Rotates the list `n` places to the left: the first `n` elements are moved to the back.
+    ///
+    /// #   Panics
+    ///
+    /// If `n > len`.
+    ///
+    /// #   Complexity
+    ///
+    /// O(min(n, len - n)), to walk from the closest end.
+    pub fn rotate_left(&mut self, n: usize, token: &mut GhostToken<'brand>) {
+        assert!(n <= self.len, "Cannot rotate by more than the length");
+
+        let mut back = self.split_off(n, token);
+
+        back.append(self, token);
+
+        mem::swap(self, &mut back);
+    }
+
+    /// Rotates the list `n` places to the right: the last `n` elements are moved to the front.
+    ///
+    /// #   Panics
+    ///
+    /// If `n > len`.
+    ///
+    /// #   Complexity
+    ///
+    /// O(min(n, len - n)), to walk from the closest end.
+    pub fn rotate_right(&mut self, n: usize, token: &mut GhostToken<'brand>) {
+        assert!(n <= self.len, "Cannot rotate by more than the length");
+
+        self.rotate_left(self.len - n, token)
+    }
+
+    /// Retains only the elements satisfying the predicate.
+    ///
+    /// #   Complexity
+    ///
+    /// O(N)
+    pub fn retain<F>(&mut self, mut fun: F, token: &mut GhostToken<'brand>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|data| fun(data), token)
+    }
+
+    /// Retains only the elements satisfying the predicate, which may modify them.
+    ///
+    /// #   Complexity
+    ///
+    /// O(N)
+    pub fn retain_mut<F>(&mut self, mut fun: F, token: &mut GhostToken<'brand>)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.extract_if(|data| !fun(data), token).for_each(drop)
+    }
+
+    /// Creates an iterator which removes, and yields, the elements satisfying the predicate.
+    ///
+    /// Elements which are not yielded, either because they do not satisfy the predicate or because the iterator is
+    /// dropped before reaching them, remain in the list.
+    pub fn extract_if<'a, F>(&'a mut self, filter: F, token: &'a mut GhostToken<'brand>)
+        -> GhostLinkedListExtractIf<'a, 'brand, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        GhostLinkedListExtractIf { cursor: self.cursor_front_mut(token), filter, }
+    }
+
+    /// Returns the element designated by the handle, if it is still in the list.
+    ///
+    /// #   Panics
+    ///
+    /// If the handle designates an element of another list.
+    pub fn get<'a>(&'a self, handle: &'a NodeHandle<'brand, T>, token: &'a GhostToken<'brand>) -> Option<&'a T> {
+        if !self.is_linked(&handle.0, token) {
+            return None;
+        }
+
+        handle.0.borrow(token).data.as_ref()
+    }
+
+    /// Returns the element designated by the handle, if it is still in the list.
+    ///
+    /// #   Panics
+    ///
+    /// If the handle designates an element of another list.
+    pub fn get_mut<'a>(&'a mut self, handle: &'a NodeHandle<'brand, T>, token: &'a mut GhostToken<'brand>)
+        -> Option<&'a mut T>
+    {
+        if !self.is_linked(&handle.0, token) {
+            return None;
+        }
+
+        handle.0.borrow_mut(token).data.as_mut()
+    }
+
+    /// Removes the element designated by the handle, if it is still in the list.
+    ///
+    /// #   Panics
+    ///
+    /// If the handle designates an element of another list.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1)
+    pub fn remove(&mut self, handle: NodeHandle<'brand, T>, token: &mut GhostToken<'brand>) -> Option<T> {
+        //  Safety:
+        //  -   The node is kept alive by `handle`, for the duration of the call.
+        let node = unsafe { unbind(&handle.0) };
+
+        if !self.is_linked(node, token) {
+            Self::reclaim(handle, token);
+            return None;
+        }
+
+        let thirds = self.unlink(node, node, 1, token);
+
+        Self::join(thirds, handle.into_inner()).data
+    }
+
+    /// Moves the element designated by the handle to the front of the list, returning whether it is still in the list.
+    ///
+    /// #   Panics
+    ///
+    /// If the handle designates an element of another list.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1)
+    pub fn move_to_front(&mut self, handle: &NodeHandle<'brand, T>, token: &mut GhostToken<'brand>) -> bool {
+        //  Safety:
+        //  -   The node is kept alive by `handle`, for the duration of the call.
+        let node = unsafe { unbind(&handle.0) };
+
+        if !self.is_linked(node, token) {
+            return false;
+        }
+
+        if !self.is_head(node) {
+            let thirds = self.unlink(node, node, 1, token);
+
+            self.push_front_node(thirds, token);
+        }
+
+        true
+    }
+
+    /// Releases the handle, leaving the element it designates in the list, if it is still there.
+    ///
+    /// #   Panics
+    ///
+    /// If the handle designates an element of another list.
+    pub fn release(&mut self, handle: NodeHandle<'brand, T>, token: &mut GhostToken<'brand>) {
+        if !self.is_linked(&handle.0, token) {
+            Self::reclaim(handle, token);
+            return;
+        }
+
+        let handle = handle.into_inner();
+
+        //  Safety:
+        //  -   The node is kept alive by the third it holds onto, and by its neighbours.
+        let node = unsafe { unbind(&handle) };
+
+        node.borrow_mut(token).own = Some(handle);
+    }
+
+    fn new_thirds(data: T, owner: usize) -> (ThirdNodePair<'brand, T>, ThirdNodePtr<'brand, T>) {
+        let node = Node { data: Some(data), prev: None, next: None, own: None, owner, };
+        let full = FullNodePtr::new(GhostNode::new(node));
+
+        let (one, others) = StaticRc::split::<1, 2>(full);
+        let (two, three) = StaticRc::split::<1, 1>(others);
+
+        ((one, two), three)
+    }
+
+    //  Creates a node, holding onto its own third.
+    fn new_node(data: T, token: &mut GhostToken<'brand>) -> ThirdNodePair<'brand, T> {
+        let (thirds, own) = Self::new_thirds(data, 0);
+
+        thirds.0.borrow_mut(token).own = Some(own);
+
+        thirds
+    }
+
+    //  Creates a node, designated by the returned handle.
+    fn new_designated_node(&mut self, data: T) -> (ThirdNodePair<'brand, T>, NodeHandle<'brand, T>) {
+        let (thirds, handle) = Self::new_thirds(data, self.id());
+
+        (thirds, NodeHandle::new(handle))
+    }
+
+    //  Pushes an item at the back of the list, without a token.
+    //
+    //  #   Safety
+    //
+    //  -   No reference to any node of the list shall exist, as is the case of a list under construction.
+    unsafe fn push_back_unshared(&mut self, data: T) {
+        let ((one, two), own) = Self::new_thirds(data, 0);
+
+        unshared(&one).own = Some(own);
+
+        self.len += 1;
+
+        if let Some(tail) = self.tail.take() {
+            unshared(&tail).next = Some(one);
+            unshared(&two).prev = Some(tail);
+
+            self.tail = Some(two);
+        } else {
+            self.head = Some(one);
+            self.tail = Some(two);
+        }
+    }
+
+    fn into_inner(left: ThirdNodePtr<'brand, T>, right: ThirdNodePtr<'brand, T>, token: &mut GhostToken<'brand>)
+        -> T
+    {
+        //  If the node still has a prev and next, they are leaked.
+        debug_assert!(left.borrow(token).prev.is_none());
+        debug_assert!(left.borrow(token).next.is_none());
+
+        if let Some(own) = left.borrow_mut(token).own.take() {
+            return Self::join((left, right), own).data.expect("Linked node should hold data");
+        }
+
+        //  The handle is outstanding: the node holds onto its own thirds until the handle is returned.
+        //
+        //  Safety:
+        //  -   The node is kept alive by the handle, and by the thirds it holds onto.
+        let node = unsafe { unbind(&left) }.borrow_mut(token);
+
+        node.prev = Some(left);
+        node.next = Some(right);
+
+        node.data.take().expect("Linked node should hold data")
+    }
+
+    fn join((one, two): ThirdNodePair<'brand, T>, three: ThirdNodePtr<'brand, T>) -> Node<'brand, T> {
+        let others: StaticRc<GhostNode<'brand, T>, 2, 3> = StaticRc::join(one, two);
+        let full = FullNodePtr::join(others, three);
+        let ghost_cell = FullNodePtr::into_inner(full);
+        let node = GhostNode::into_inner(ghost_cell);
+
+        //  If the node still has a prev and next, they are leaked.
+        debug_assert!(node.prev.is_none());
+        debug_assert!(node.next.is_none());
+
+        node
+    }
+
+    //  Reclaims a node removed from the list while designated by the handle.
+    fn reclaim(handle: NodeHandle<'brand, T>, token: &mut GhostToken<'brand>) {
+        let handle = handle.into_inner();
+        let node = handle.borrow_mut(token);
+
+        let left = node.prev.take().expect("Removed node should hold onto its thirds");
+        let right = node.next.take().expect("Removed node should hold onto its thirds");
+
+        Self::join((left, right), handle);
+    }
+
+    //  Returns whether the node, designated by a handle, is still linked in the list.
+    //
+    //  #   Panics
+    //
+    //  If the node is linked in another list.
+    fn is_linked(&self, node: &GhostNode<'brand, T>, token: &GhostToken<'brand>) -> bool {
+        let node = node.borrow(token);
+
+        if node.data.is_none() {
+            return false;
+        }
+
+        assert!(self.id != 0 && node.owner == self.id, "Handle should belong to this list");
+
+        true
+    }
+
+    //  Returns the identifier of the list, assigning one if need be.
+    fn id(&mut self) -> usize {
+        if self.id == 0 {
+            self.id = new_id();
+        }
+
+        self.id
+    }
+
+    //  Gives the list and `other`, whose elements are about to be moved into the list, a common identifier.
+    //
+    //  The elements of the shorter of the two are relabelled, unless the list has no identifier, hence no designated
+    //  element, and simply adopts that of `other`.
+    fn merge_id(&mut self, other: &mut Self, token: &mut GhostToken<'brand>) {
+        let id = mem::take(&mut other.id);
+
+        if id == 0 {
+            return;
+        }
+
+        if self.id == 0 {
+            self.id = id;
+        } else if self.len < other.len {
+            self.relabel(id, token);
+            self.id = id;
+        } else {
+            other.relabel(self.id, token);
+        }
+    }
+
+    //  Gives `other`, whose elements were just split off the list, an identifier of its own.
+    //
+    //  The elements of the shorter of the two are relabelled with a new identifier, unless the list has no identifier,
+    //  hence no designated element.
+    fn split_id(&mut self, other: &mut Self, token: &mut GhostToken<'brand>) {
+        if self.id == 0 {
+            return;
+        }
+
+        let id = new_id();
+
+        if other.len < self.len {
+            other.relabel(id, token);
+            other.id = id;
+        } else {
+            self.relabel(id, token);
+            other.id = mem::replace(&mut self.id, id);
+        }
+    }
+
+    //  Labels every node of the list with `id`.
+    fn relabel(&self, id: usize, token: &mut GhostToken<'brand>) {
+        //  Safety:
+        //  -   The nodes remain linked in the list, hence alive, for the duration of the call.
+        let mut current = self.head.as_deref().map(|node| unsafe { unbind(node) });
+
+        while let Some(node) = current {
+            let node = node.borrow_mut(token);
+
+            node.owner = id;
+
+            //  Safety:
+            //  -   As above.
+            current = node.next.as_deref().map(|node| unsafe { unbind(node) });
+        }
+    }
+
+    //  Splits off, and returns, the first `n` elements of the list, or the whole list if shorter.
+    fn split_front(&mut self, n: usize, token: &mut GhostToken<'brand>) -> Self {
+        let back = self.split_off(n.min(self.len), token);
+
+        mem::replace(self, back)
+    }
+
+    //  Unlinks the front node, if any, returning its linked thirds.
+    fn pop_front_node(&mut self, token: &mut GhostToken<'brand>) -> Option<ThirdNodePair<'brand, T>> {
+        //  Safety:
+        //  -   The head is linked in the list, hence alive, for the duration of the call.
+        let head = self.head.as_deref().map(|node| unsafe { unbind(node) })?;
+
+        Some(self.unlink(head, head, 1, token))
+    }
+
+    //  Merges the sorted `left` and `right` at the back of `sorted`, taking from `left` first on ties.
+    fn merge<F>(sorted: &mut Self, left: &mut Self, right: &mut Self, compare: &mut F, token: &mut GhostToken<'brand>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        while let (Some(l), Some(r)) = (left.front(token), right.front(token)) {
+            let from = if compare(r, l) == Ordering::Less { &mut *right } else { &mut *left };
+
+            let thirds = from.pop_front_node(token).expect("Non-empty list should have a front node");
+
+            sorted.push_back_node(thirds, token);
+        }
+
+        sorted.append(left, token);
+        sorted.append(right, token);
+    }
+
+    fn is_head(&self, node: &GhostNode<'brand, T>) -> bool {
+        self.head.as_deref().is_some_and(|head| ptr::eq(head, node))
+    }
+
+    fn is_tail(&self, node: &GhostNode<'brand, T>) -> bool {
+        self.tail.as_deref().is_some_and(|tail| ptr::eq(tail, node))
+    }
+
+    //  Returns the slot holding the third of the node following `node`.
+    //
+    //  `None` designates the "ghost" position before the head.
+    fn next_slot<'a>(&'a mut self, node: Option<&'a GhostNode<'brand, T>>, token: &'a mut GhostToken<'brand>)
+        -> &'a mut Option<ThirdNodePtr<'brand, T>>
+    {
+        match node {
+            Some(node) => &mut node.borrow_mut(token).next,
+            None => &mut self.head,
+        }
+    }
+
+    //  Returns the slot holding the third of the node preceding `node`.
+    //
+    //  `None` designates the "ghost" position after the tail.
+    fn prev_slot<'a>(&'a mut self, node: Option<&'a GhostNode<'brand, T>>, token: &'a mut GhostToken<'brand>)
+        -> &'a mut Option<ThirdNodePtr<'brand, T>>
+    {
+        match node {
+            Some(node) => &mut node.borrow_mut(token).prev,
+            None => &mut self.tail,
+        }
+    }
+
+    //  Links the detached chain `first..=last`, of `len` nodes, between `prev` and `next`, which should be adjacent.
+    //
+    //  `None` designates the "ghost" position: before the head for `prev`, after the tail for `next`.
+    fn link(
+        &mut self,
+        prev: Option<&GhostNode<'brand, T>>,
+        next: Option<&GhostNode<'brand, T>>,
+        (first, last): ThirdNodePair<'brand, T>,
+        len: usize,
+        token: &mut GhostToken<'brand>,
+    )
+    {
+        self.len += len;
+
+        let to_next = self.next_slot(prev, token).take();
+        let to_prev = self.prev_slot(next, token).take();
+
+        first.borrow_mut(token).prev = to_prev;
+        last.borrow_mut(token).next = to_next;
+
+        *self.next_slot(prev, token) = Some(first);
+        *self.prev_slot(next, token) = Some(last);
+    }
+
+    //  Unlinks the chain `first..=last`, of `len` nodes, returning its extremities.
+    //
+    //  If `first` and `last` are the same node, its two linked thirds are returned.
+    fn unlink(
+        &mut self,
+        first: &GhostNode<'brand, T>,
+        last: &GhostNode<'brand, T>,
+        len: usize,
+        token: &mut GhostToken<'brand>,
+    )
+        -> ThirdNodePair<'brand, T>
+    {
+        self.len -= len;
+
+        let before = first.borrow_mut(token).prev.take();
+        let after = last.borrow_mut(token).next.take();
+
+        //  Safety:
+        //  -   The neighbours remain linked in the list, hence alive, for the duration of the call.
+        let (prev, next) = unsafe { (before.as_deref().map(|n| unbind(n)), after.as_deref().map(|n| unbind(n))) };
+
+        let first = self.next_slot(prev, token).take().expect("Linked node should be referenced by its predecessor");
+        let last = self.prev_slot(next, token).take().expect("Linked node should be referenced by its successor");
+
+        *self.next_slot(prev, token) = after;
+        *self.prev_slot(next, token) = before;
+
+        (first, last)
+    }
+}
+
+impl<'brand, T> Default for GhostLinkedList<'brand, T> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<'brand, T: GhostDebug<'brand>> GhostDebug<'brand> for GhostLinkedList<'brand, T> {
+    fn ghost_fmt(&self, token: &GhostToken<'brand>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter(token).map(|value| WithToken::new(value, token))).finish()
+    }
+}
+
+//  Safety:
+//  -   A new node is created for each element.
+unsafe impl<'brand, T: GhostClone<'brand>> GhostClone<'brand> for GhostLinkedList<'brand, T> {
+    fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self {
+        let mut clone = Self::default();
+
+        for value in self.iter(token) {
+            //  Safety:
+            //  -   `clone` is under construction, hence none of its nodes is referenced.
+            unsafe { clone.push_back_unshared(value.ghost_clone(context, token)) };
+        }
+
+        clone
+    }
+}
+
+//  Serialized as a sequence of its elements.
+#[cfg(feature = "serde")]
+impl<'brand, T: GhostSerialize<'brand>> GhostSerialize<'brand> for GhostLinkedList<'brand, T> {
+    fn ghost_serialize<S: Serializer>(&self, serializer: S, token: &GhostToken<'brand>) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter(token).map(|value| WithToken::new(value, token)))
+    }
+}
+
+impl<'brand, T: GhostPartialEq<'brand>> GhostPartialEq<'brand> for GhostLinkedList<'brand, T> {
+    fn ghost_eq(&self, other: &Self, token: &GhostToken<'brand>) -> bool {
+        ghost_cmp::iter_eq(self.iter(token), other.iter(token), token)
+    }
+}
+
+impl<'brand, T: GhostEq<'brand>> GhostEq<'brand> for GhostLinkedList<'brand, T> {}
+
+impl<'brand, T: GhostPartialOrd<'brand>> GhostPartialOrd<'brand> for GhostLinkedList<'brand, T> {
+    fn ghost_partial_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Option<Ordering> {
+        ghost_cmp::iter_partial_cmp(self.iter(token), other.iter(token), token)
+    }
+}
+
+impl<'brand, T: GhostOrd<'brand>> GhostOrd<'brand> for GhostLinkedList<'brand, T> {
+    fn ghost_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Ordering {
+        ghost_cmp::iter_cmp(self.iter(token), other.iter(token), token)
+    }
+}
+
+impl<'brand, T: GhostHash<'brand>> GhostHash<'brand> for GhostLinkedList<'brand, T> {
+    fn ghost_hash<H: Hasher>(&self, state: &mut H, token: &GhostToken<'brand>) {
+        ghost_cmp::iter_hash(self.iter(token), state, token)
+    }
+}
+
+//  Safety:
+//  -   `'static` is the brand, and only the brand.
+unsafe impl<'id, T> GhostProject<'id> for GhostLinkedList<'static, T> {
+    type Branded = GhostLinkedList<'id, T>;
+}
+
+impl<'id, T> GhostUnproject<'id> for GhostLinkedList<'id, T> {
+    type Unbranded = GhostLinkedList<'static, T>;
+}
+
+//  Safety:
+//  -   The elements are not projected, hence no token within could be a token of the brand.
+unsafe impl<'id, T> ProjectField<'id> for GhostLinkedList<'static, T> {}
+
+impl<'id, T> GhostDrop<'id> for GhostLinkedList<'static, T> {
+    fn ghost_drop(this: &mut GhostLinkedList<'id, T>, token: &mut GhostToken<'id>) { this.clear(token) }
+}
+
+/// An iterator over a GhostLinkedList, self-sufficient once created as it carries its own token.
+pub struct GhostLinkedListIterator<'a, 'brand, T> {
+    token: &'a GhostToken<'brand>,
+    head_tail: Option<NodeEnds<'a, 'brand, T>>,
+    len: usize,
+}
+
+impl<'a, 'brand, T> Clone for GhostLinkedListIterator<'a, 'brand, T> {
+    fn clone(&self) -> Self { Self { token: self.token, head_tail: self.head_tail, len: self.len, } }
+}
+
+impl<'a, 'id, T> Iterator for GhostLinkedListIterator<'a, 'id, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        let node = head.borrow(self.token);
+
+        self.len -= 1;
+
+        if self.len > 0 {
+            self.head_tail = node.next.as_ref().map(|n| {
+                let n: &'a GhostNode<'_, _> = n;
+                (n, tail)
+            });
+        }
+
+        Some(node.data())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.len, Some(self.len)) }
+}
+
+impl<'a, 'id, T> DoubleEndedIterator for GhostLinkedListIterator<'a, 'id, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        let node = tail.borrow(self.token);
+
+        self.len -= 1;
+
+        if self.len > 0 {
+            self.head_tail = node.prev.as_ref().map(|n| {
+                let n: &'a GhostNode<'_, _> = n;
+                (head, n)
+            });
+        }
+
+        Some(node.data())
+    }
+}
+
+impl<'a, 'id, T> ExactSizeIterator for GhostLinkedListIterator<'a, 'id, T> {}
+
+impl<'a, 'id, T> FusedIterator for GhostLinkedListIterator<'a, 'id, T> {}
+
+/// A mutable iterator over a GhostLinkedList, self-sufficient once created as it carries its own token.
+pub struct GhostLinkedListIteratorMut<'a, 'brand, T> {
+    token: &'a mut GhostToken<'brand>,
+    head_tail: Option<NodeEnds<'a, 'brand, T>>,
+    len: usize,
+}
+
+impl<'a, 'id, T> Iterator for GhostLinkedListIteratorMut<'a, 'id, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        let node = head.borrow_mut(self.token);
+
+        self.len -= 1;
+
+        if self.len > 0 {
+            self.head_tail = node.next.as_deref().map(|n| (Self::extend(n), tail));
+        }
+
+        Some(Self::extend_mut(node.data_mut()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.len, Some(self.len)) }
+}
+
+impl<'a, 'id, T> DoubleEndedIterator for GhostLinkedListIteratorMut<'a, 'id, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (head, tail) = self.head_tail.take()?;
+
+        let node = tail.borrow_mut(self.token);
+
+        self.len -= 1;
+
+        if self.len > 0 {
+            self.head_tail = node.prev.as_deref().map(|n| (head, Self::extend(n)));
+        }
+
+        Some(Self::extend_mut(node.data_mut()))
+    }
+}
+
+impl<'a, 'id, T> ExactSizeIterator for GhostLinkedListIteratorMut<'a, 'id, T> {}
+
+impl<'a, 'id, T> FusedIterator for GhostLinkedListIteratorMut<'a, 'id, T> {}
+
+impl<'a, 'id, T> GhostLinkedListIteratorMut<'a, 'id, T> {
+    //  Extends the lifetime of a reference to a node to `'a`.
+    fn extend(node: &GhostNode<'id, T>) -> &'a GhostNode<'id, T> {
+        //  Safety:
+        //  -   The token is borrowed for `'a`, hence the list cannot be modified and its nodes live for `'a`.
+        unsafe { &*(node as *const GhostNode<'id, T>) }
+    }
+
+    //  Extends the lifetime of a reference to an element to `'a`.
+    fn extend_mut(data: &mut T) -> &'a mut T {
+        //  Safety:
+        //  -   The token is borrowed for `'a`, hence the list cannot be modified and its nodes live for `'a`.
+        //  -   Each node is yielded at most once, as `len` prevents the two ends from crossing, hence the references
+        //      handed out never alias.
+        unsafe { &mut *(data as *mut T) }
+    }
+}
+
+/// A cursor over a GhostLinkedList, self-sufficient once created as it carries its own token.
+///
+/// The cursor points either to an element, or to the "ghost" non-element between the back and the front of the list.
+pub struct GhostLinkedListCursor<'a, 'brand, T> {
+    token: &'a GhostToken<'brand>,
+    list: &'a GhostLinkedList<'brand, T>,
+    current: Option<&'a GhostNode<'brand, T>>,
+    //  Invariant: if `current` is `Some`, index of `current` in `list`.
+    index: usize,
+}
+
+impl<'a, 'brand, T> GhostLinkedListCursor<'a, 'brand, T> {
+    /// Returns the index of the current element, or `None` if at the "ghost" element.
+    pub fn index(&self) -> Option<usize> { self.current.map(|_| self.index) }
+
+    /// Moves to the next element, or the "ghost" element if at the back; moves to the front if at the "ghost" element.
+    pub fn move_next(&mut self) {
+        self.index = next_index(self.current.is_some(), self.index);
+        self.current = self.next_node();
+    }
+
+    /// Moves to the previous element, or the "ghost" element if at the front; moves to the back if at the "ghost"
+    /// element.
+    pub fn move_prev(&mut self) {
+        self.index = prev_index(self.current.is_some(), self.index, self.list.len);
+        self.current = self.prev_node();
+    }
+
+    /// Returns the current element, if any.
+    pub fn current(&self) -> Option<&'a T> { self.current.map(|node| node.borrow(self.token).data()) }
+
+    /// Returns the next element, if any.
+    pub fn peek_next(&self) -> Option<&'a T> { self.next_node().map(|node| node.borrow(self.token).data()) }
+
+    /// Returns the previous element, if any.
+    pub fn peek_prev(&self) -> Option<&'a T> { self.prev_node().map(|node| node.borrow(self.token).data()) }
+
+    /// Returns the front element of the list, if any.
+    pub fn front(&self) -> Option<&'a T> { self.list.front(self.token) }
+
+    /// Returns the back element of the list, if any.
+    pub fn back(&self) -> Option<&'a T> { self.list.back(self.token) }
+
+    fn next_node(&self) -> Option<&'a GhostNode<'brand, T>> {
+        match self.current {
+            Some(node) => node.borrow(self.token).next.as_deref(),
+            None => self.list.head.as_deref(),
+        }
+    }
+
+    fn prev_node(&self) -> Option<&'a GhostNode<'brand, T>> {
+        match self.current {
+            Some(node) => node.borrow(self.token).prev.as_deref(),
+            None => self.list.tail.as_deref(),
+        }
+    }
+}
+
+impl<'a, 'brand, T> Clone for GhostLinkedListCursor<'a, 'brand, T> {
+    fn clone(&self) -> Self { Self { token: self.token, list: self.list, current: self.current, index: self.index, } }
+}
+
+/// A mutable cursor over a GhostLinkedList, self-sufficient once created as it carries its own token.
+///
+/// The cursor points either to an element, or to the "ghost" non-element between the back and the front of the list.
+pub struct GhostLinkedListCursorMut<'a, 'brand, T> {
+    token: &'a mut GhostToken<'brand>,
+    list: &'a mut GhostLinkedList<'brand, T>,
+    //  Invariant: if `Some`, points to a node linked in `list`.
+    current: Option<NonNull<GhostNode<'brand, T>>>,
+    //  Invariant: if `current` is `Some`, index of `current` in `list`.
+    index: usize,
+}
+
+impl<'a, 'brand, T> GhostLinkedListCursorMut<'a, 'brand, T> {
+    /// Returns the index of the current element, or `None` if at the "ghost" element.
+    pub fn index(&self) -> Option<usize> { self.current.map(|_| self.index) }
+
+    /// Moves to the next element, or the "ghost" element if at the back; moves to the front if at the "ghost" element.
+    pub fn move_next(&mut self) {
+        self.index = next_index(self.current.is_some(), self.index);
+        self.current = self.next_node().map(NonNull::from);
+    }
+
+    /// Moves to the previous element, or the "ghost" element if at the front; moves to the back if at the "ghost"
+    /// element.
+    pub fn move_prev(&mut self) {
+        self.index = prev_index(self.current.is_some(), self.index, self.list.len);
+        self.current = self.prev_node().map(NonNull::from);
+    }
+
+    /// Returns the current element, if any.
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current_node().map(move |node| node.borrow_mut(self.token).data_mut())
+    }
+
+    /// Returns the next element, if any.
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        self.next_node().map(move |node| node.borrow_mut(self.token).data_mut())
+    }
+
+    /// Returns the previous element, if any.
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        self.prev_node().map(move |node| node.borrow_mut(self.token).data_mut())
+    }
+
+    /// Returns a read-only cursor pointing to the current element, borrowing this cursor.
+    pub fn as_cursor(&self) -> GhostLinkedListCursor<'_, 'brand, T> {
+        GhostLinkedListCursor { token: self.token, list: self.list, current: self.current_node(), index: self.index, }
+    }
+
+    /// Inserts an element after the current one; at the front if the cursor points to the "ghost" element.
+    pub fn insert_after(&mut self, data: T) {
+        let thirds = GhostLinkedList::new_node(data, self.token);
+
+        self.list.link(self.current_node(), self.next_node(), thirds, 1, self.token);
+    }
+
+    /// Inserts an element after the current one, and returns a handle to it; at the front if the cursor points to the
+    /// "ghost" element.
+    pub fn insert_after_with_handle(&mut self, data: T) -> NodeHandle<'brand, T> {
+        let (thirds, handle) = self.list.new_designated_node(data);
+
+        self.list.link(self.current_node(), self.next_node(), thirds, 1, self.token);
+
+        handle
+    }
+
+    /// Inserts an element before the current one; at the back if the cursor points to the "ghost" element.
+    pub fn insert_before(&mut self, data: T) {
+        let thirds = GhostLinkedList::new_node(data, self.token);
+
+        self.list.link(self.prev_node(), self.current_node(), thirds, 1, self.token);
+        self.index += self.current.is_some() as usize;
+    }
+
+    /// Inserts an element before the current one, and returns a handle to it; at the back if the cursor points to the
+    /// "ghost" element.
+    pub fn insert_before_with_handle(&mut self, data: T) -> NodeHandle<'brand, T> {
+        let (thirds, handle) = self.list.new_designated_node(data);
+
+        self.list.link(self.prev_node(), self.current_node(), thirds, 1, self.token);
+        self.index += self.current.is_some() as usize;
+
+        handle
+    }
+
+    /// Removes the current element, if any, and moves to the next one.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current_node()?;
+        let next = self.next_node().map(NonNull::from);
+
+        let (one, two) = self.list.unlink(node, node, 1, self.token);
+
+        self.current = next;
+
+        Some(GhostLinkedList::into_inner(one, two, self.token))
+    }
+
+    /// Inserts the elements of `list` after the current one; at the front if the cursor points to the "ghost" element.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1), or O(min(len, list.len)) if both lists handed out handles, see `NodeHandle`.
+    pub fn splice_after(&mut self, mut list: GhostLinkedList<'brand, T>) {
+        self.list.merge_id(&mut list, self.token);
+
+        if let (Some(first), Some(last)) = (list.head.take(), list.tail.take()) {
+            self.list.link(self.current_node(), self.next_node(), (first, last), mem::take(&mut list.len), self.token);
+        }
+    }
+
+    /// Inserts the elements of `list` before the current one; at the back if the cursor points to the "ghost" element.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1), or O(min(len, list.len)) if both lists handed out handles, see `NodeHandle`.
+    pub fn splice_before(&mut self, mut list: GhostLinkedList<'brand, T>) {
+        self.list.merge_id(&mut list, self.token);
+
+        if let (Some(first), Some(last)) = (list.head.take(), list.tail.take()) {
+            let len = mem::take(&mut list.len);
+
+            self.list.link(self.prev_node(), self.current_node(), (first, last), len, self.token);
+            self.index += if self.current.is_some() { len } else { 0 };
+        }
+    }
+
+    /// Splits the list after the current element, returning the elements after it.
+    ///
+    /// If the cursor points to the "ghost" element, the whole list is returned.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1), or linear in the length of the shorter part if the list handed out handles, see `NodeHandle`.
+    pub fn split_after(&mut self) -> GhostLinkedList<'brand, T> {
+        //  Safety:
+        //  -   The tail is linked in the list, hence alive, for the duration of the call.
+        let last = self.list.tail.as_deref().map(|node| unsafe { unbind(node) });
+
+        let len = match self.index() {
+            Some(index) => self.list.len - index - 1,
+            None => self.list.len,
+        };
+
+        match (self.next_node(), last) {
+            (Some(first), Some(last)) => self.split(first, last, len),
+            _ => GhostLinkedList::default(),
+        }
+    }
+
+    /// Splits the list before the current element, returning the elements before it.
+    ///
+    /// If the cursor points to the "ghost" element, the whole list is returned.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1), or linear in the length of the shorter part if the list handed out handles, see `NodeHandle`.
+    pub fn split_before(&mut self) -> GhostLinkedList<'brand, T> {
+        //  Safety:
+        //  -   The head is linked in the list, hence alive, for the duration of the call.
+        let first = self.list.head.as_deref().map(|node| unsafe { unbind(node) });
+
+        let len = self.index().unwrap_or(self.list.len);
+
+        match (first, self.prev_node()) {
+            (Some(first), Some(last)) => {
+                self.index = 0;
+                self.split(first, last, len)
+            },
+            _ => GhostLinkedList::default(),
+        }
+    }
+
+    /// Splits the list at the current element, returning it and the elements after it, and moves to the "ghost"
+    /// element.
+    ///
+    /// If the cursor points to the "ghost" element, an empty list is returned.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1), or linear in the length of the shorter part if the list handed out handles, see `NodeHandle`.
+    pub fn split_off(&mut self) -> GhostLinkedList<'brand, T> {
+        //  Safety:
+        //  -   The tail is linked in the list, hence alive, for the duration of the call.
+        let last = self.list.tail.as_deref().map(|node| unsafe { unbind(node) });
+
+        match (self.current_node(), last) {
+            (Some(first), Some(last)) => {
+                let list = self.split(first, last, self.list.len - self.index);
+                self.current = None;
+                list
+            },
+            _ => GhostLinkedList::default(),
+        }
+    }
+
+    fn split(&mut self, first: &GhostNode<'brand, T>, last: &GhostNode<'brand, T>, len: usize)
+        -> GhostLinkedList<'brand, T>
+    {
+        let (first, last) = self.list.unlink(first, last, len, self.token);
+
+        let mut list = GhostLinkedList { head: Some(first), tail: Some(last), len, id: 0, };
+
+        self.list.split_id(&mut list, self.token);
+
+        list
+    }
+
+    //  The returned reference should not be used past the removal of the node from the list.
+    fn current_node<'x>(&self) -> Option<&'x GhostNode<'brand, T>> {
+        //  Safety:
+        //  -   As per invariant, `current` points to a node linked in the list, hence alive.
+        self.current.map(|node| unsafe { unbind(node.as_ref()) })
+    }
+
+    //  The returned reference should not be used past the removal of the node from the list.
+    fn next_node<'x>(&self) -> Option<&'x GhostNode<'brand, T>> {
+        let next = match self.current_node() {
+            Some(node) => node.borrow(self.token).next.as_deref(),
+            None => self.list.head.as_deref(),
+        };
+
+        //  Safety:
+        //  -   The node is linked in the list, hence alive.
+        next.map(|node| unsafe { unbind(node) })
+    }
+
+    //  The returned reference should not be used past the removal of the node from the list.
+    fn prev_node<'x>(&self) -> Option<&'x GhostNode<'brand, T>> {
+        let prev = match self.current_node() {
+            Some(node) => node.borrow(self.token).prev.as_deref(),
+            None => self.list.tail.as_deref(),
+        };
+
+        //  Safety:
+        //  -   The node is linked in the list, hence alive.
+        prev.map(|node| unsafe { unbind(node) })
+    }
+}
+
+/// An iterator removing, and yielding, the elements of a GhostLinkedList satisfying a predicate.
+///
+/// Created by `GhostLinkedList::extract_if`.
+pub struct GhostLinkedListExtractIf<'a, 'brand, T, F> {
+    cursor: GhostLinkedListCursorMut<'a, 'brand, T>,
+    filter: F,
+}
+
+impl<'a, 'brand, T, F> Iterator for GhostLinkedListExtractIf<'a, 'brand, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while let Some(data) = self.cursor.current() {
+            if (self.filter)(data) {
+                return self.cursor.remove_current();
+            }
+
+            self.cursor.move_next();
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.cursor.index().map_or(0, |index| self.cursor.list.len - index);
+
+        (0, Some(remaining))
+    }
+}
+
+impl<'a, 'brand, T, F> FusedIterator for GhostLinkedListExtractIf<'a, 'brand, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{}
+
+/// A handle to an element of a GhostLinkedList, for O(1) access, removal, and moving.
+///
+/// The brand of the handle prevents its use with a list of another brand. The lists of a brand are told apart at run
+/// time: a list handing out handles is assigned an identifier, which labels each of its elements, and using a handle
+/// with another list panics. As a result, moving elements between lists which handed out handles, such as by `append`
+/// or `split_off`, relabels the elements of the shorter of the two, in O(min(len, other.len)) rather than O(1).
+///
+/// The handle should be returned to the list, via `remove` or `release`. Should it be dropped instead, the element
+/// remains in the list, and is dropped or moved out as usual when removed, but its node -- a few pointers -- is leaked.
+/// Should the element be removed from the list by other means in the meantime, such as `pop_front`, its node is
+/// retained until the handle is returned.
+#[must_use = "the handle should be returned to the list, otherwise its node is leaked"]
+pub struct NodeHandle<'brand, T>(ManuallyDrop<ThirdNodePtr<'brand, T>>);
+
+//  Safety:
+//  -   `'static` is the brand, and only the brand.
+unsafe impl<'id, T> GhostProject<'id> for NodeHandle<'static, T> {
+    type Branded = NodeHandle<'id, T>;
+}
+
+impl<'id, T> GhostUnproject<'id> for NodeHandle<'id, T> {
+    type Unbranded = NodeHandle<'static, T>;
+}
+
+//  Safety:
+//  -   The element is not projected, hence no token within could be a token of the brand.
+unsafe impl<'id, T> ProjectField<'id> for NodeHandle<'static, T> {}
+
+//
+//  Implementation
+//
+
+//  A dropped `NodeHandle` leaks its third, rather than dropping it, which would panic in debug builds.
+impl<'brand, T> NodeHandle<'brand, T> {
+    fn new(third: ThirdNodePtr<'brand, T>) -> Self { Self(ManuallyDrop::new(third)) }
+
+    fn into_inner(self) -> ThirdNodePtr<'brand, T> { ManuallyDrop::into_inner(self.0) }
+}
+
+//  Each node is shared in thirds: one held by its predecessor -- or the head, one held by its successor -- or the tail,
+//  and one held by itself, in `own` -- or by the `NodeHandle` handed out, in which case `own` is `None`.
+//
+//  Should the node be removed from the list while its `NodeHandle` is outstanding, then `data` is moved out, and the
+//  node holds onto its two other thirds in `prev` and `next` until the `NodeHandle` is returned. Should the handle be
+//  dropped instead, the node is leaked, but not its element.
+struct Node<'brand, T> {
+    data: Option<T>,
+    prev: Option<ThirdNodePtr<'brand, T>>,
+    next: Option<ThirdNodePtr<'brand, T>>,
+    own: Option<ThirdNodePtr<'brand, T>>,
+    //  The identifier of the list the node is linked in, if designated by a `NodeHandle`.
+    owner: usize,
+}
+
+impl<'brand, T> Node<'brand, T> {
+    fn data(&self) -> &T { self.data.as_ref().expect("Linked node should hold data") }
+
+    fn data_mut(&mut self) -> &mut T { self.data.as_mut().expect("Linked node should hold data") }
+}
+
+type GhostNode<'brand, T> = GhostCell<'brand, Node<'brand, T>>;
+type ThirdNodePtr<'brand, T> = StaticRc<GhostNode<'brand, T>, 1, 3>;
+type ThirdNodePair<'brand, T> = (ThirdNodePtr<'brand, T>, ThirdNodePtr<'brand, T>);
+type FullNodePtr<'brand, T> = StaticRc<GhostNode<'brand, T>, 3, 3>;
+type NodeEnds<'a, 'brand, T> = (&'a GhostNode<'brand, T>, &'a GhostNode<'brand, T>);
+
+//  Returns the index of a cursor after moving to the next node, from the node at `index` if `is_node`, or from the
+//  "ghost" element otherwise.
+fn next_index(is_node: bool, index: usize) -> usize { if is_node { index + 1 } else { 0 } }
+
+//  Returns the index of a cursor after moving to the previous node, from the node at `index` if `is_node`, or from
+//  the "ghost" element otherwise.
+//
+//  Moving from the front node to the "ghost" element yields a meaningless index, which is ignored.
+fn prev_index(is_node: bool, index: usize, len: usize) -> usize {
+    if is_node { index.wrapping_sub(1) } else { len.wrapping_sub(1) }
+}
+
+//  Returns a new list identifier, distinct from 0 and from any identifier previously returned.
+fn new_id() -> usize {
+    static NEXT: AtomicUsize = AtomicUsize::new(1);
+
+    NEXT.fetch_update(atomic::Ordering::Relaxed, atomic::Ordering::Relaxed, |id| id.checked_add(1))
+        .expect("List identifiers should not be exhausted")
+}
+
+//  Unbinds the lifetime of the reference to a node from that of the third it was obtained from.
+//
+//  #   Safety
+//
+//  -   The caller should ensure that the node outlives `'x`, that is that at least one of its thirds outlives `'x`.
+unsafe fn unbind<'x, 'brand, T>(node: &GhostNode<'brand, T>) -> &'x GhostNode<'brand, T> {
+    &*(node as *const GhostNode<'brand, T>)
+}
+
+//  Borrows the node mutably, without a token.
+//
+//  #   Safety
+//
+//  -   The caller should ensure that no other reference to the node exists for `'x`, and that the node outlives `'x`.
+unsafe fn unshared<'x, 'brand, T>(node: &ThirdNodePtr<'brand, T>) -> &'x mut Node<'brand, T> {
+    (*StaticRc::as_ptr(node).as_ptr()).get_mut()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{panic::{self, AssertUnwindSafe}, rc::Rc};
+
+    use super::*;
+
+    fn with_list<R, F>(fun: F) -> R
+    where
+        for<'brand> F: FnOnce(&mut GhostLinkedList<'brand, String>, &mut GhostToken<'brand>) -> R,
+    {
+        GhostToken::new(|mut token| {
+            let mut list = GhostLinkedList::new();
+
+            let result = fun(&mut list, &mut token);
+
+            list.clear(&mut token);
+
+            result
+        })
+    }
+
+    fn collect<'brand>(list: &GhostLinkedList<'brand, String>, token: &GhostToken<'brand>) -> Vec<String> {
+        list.iter(token).cloned().collect()
+    }
+
+    #[test]
+    fn empty() {
+        with_list(|list, token| {
+            assert!(list.is_empty());
+            assert_eq!(0, list.len());
+
+            assert_eq!(None, list.front(token));
+            assert_eq!(None, list.back(token));
+            assert_eq!(None, list.front_mut(token));
+            assert_eq!(None, list.back_mut(token));
+            assert_eq!(None, list.pop_front(token));
+            assert_eq!(None, list.pop_back(token));
+
+            assert_eq!(None, list.iter(token).next());
+            assert_eq!(None, list.iter(token).next_back());
+        });
+    }
+
+    #[test]
+    fn push_pop_front() {
+        with_list(|list, token| {
+            list.push_front("1".to_string(), token);
+            list.push_front("2".to_string(), token);
+            list.push_front("3".to_string(), token);
+
+            assert!(!list.is_empty());
+            assert_eq!(3, list.len());
+            assert_eq!(vec!["3", "2", "1"], collect(list, token));
+
+            assert_eq!(Some("3".to_string()), list.pop_front(token));
+            assert_eq!(Some("2".to_string()), list.pop_front(token));
+            assert_eq!(Some("1".to_string()), list.pop_front(token));
+            assert_eq!(None, list.pop_front(token));
+
+            assert!(list.is_empty());
+        });
+    }
+
+    #[test]
+    fn push_pop_back() {
+        with_list(|list, token| {
+            list.push_back("1".to_string(), token);
+            list.push_back("2".to_string(), token);
+            list.push_back("3".to_string(), token);
+
+            assert_eq!(3, list.len());
+            assert_eq!(vec!["1", "2", "3"], collect(list, token));
+
+            assert_eq!(Some("3".to_string()), list.pop_back(token));
+            assert_eq!(Some("2".to_string()), list.pop_back(token));
+            assert_eq!(Some("1".to_string()), list.pop_back(token));
+            assert_eq!(None, list.pop_back(token));
+
+            assert!(list.is_empty());
+        });
+    }
+
+    #[test]
+    fn push_pop_mixed() {
+        with_list(|list, token| {
+            list.push_back("2".to_string(), token);
+            list.push_front("1".to_string(), token);
+            list.push_back("3".to_string(), token);
+
+            assert_eq!(vec!["1", "2", "3"], collect(list, token));
+
+            assert_eq!(Some("3".to_string()), list.pop_back(token));
+            assert_eq!(Some("1".to_string()), list.pop_front(token));
+            assert_eq!(Some("2".to_string()), list.pop_back(token));
+            assert_eq!(None, list.pop_front(token));
+        });
+    }
+
+    #[test]
+    fn front_back() {
+        with_list(|list, token| {
+            list.push_back("1".to_string(), token);
+            list.push_back("2".to_string(), token);
+
+            assert_eq!(Some("1"), list.front(token).map(|s| &**s));
+            assert_eq!(Some("2"), list.back(token).map(|s| &**s));
+
+            list.front_mut(token).unwrap().push('!');
+            list.back_mut(token).unwrap().push('?');
+
+            assert_eq!(vec!["1!", "2?"], collect(list, token));
+        });
+    }
+
+    #[test]
+    fn iter_reverse() {
+        with_list(|list, token| {
+            for i in 0..4 {
+                list.push_back(i.to_string(), token);
+            }
+
+            let reversed: Vec<_> = list.iter(token).rev().cloned().collect();
+
+            assert_eq!(vec!["3", "2", "1", "0"], reversed);
+        });
+    }
+
+    #[test]
+    fn iter_both_ends() {
+        with_list(|list, token| {
+            for i in 0..3 {
+                list.push_back(i.to_string(), token);
+            }
+
+            let mut iter = list.iter(token);
+
+            assert_eq!(3, iter.len());
+            assert_eq!(Some("0"), iter.next().map(|s| &**s));
+            assert_eq!(Some("2"), iter.next_back().map(|s| &**s));
+            assert_eq!(Some("1"), iter.next_back().map(|s| &**s));
+            assert_eq!(None, iter.next());
+            assert_eq!(None, iter.next_back());
+        });
+    }
+
+    #[test]
+    fn iter_mut() {
+        with_list(|list, token| {
+            for i in 0..4 {
+                list.push_back(i.to_string(), token);
+            }
+
+            let mut iter = list.iter_mut(token);
+
+            let (first, last) = (iter.next().unwrap(), iter.next_back().unwrap());
+
+            for s in iter {
+                s.push('?');
+            }
+
+            first.push('!');
+            last.push('.');
+
+            assert_eq!(vec!["0!", "1?", "2?", "3."], collect(list, token));
+        });
+    }
+
+    #[test]
+    fn clear() {
+        with_list(|list, token| {
+            list.push_back("1".to_string(), token);
+            list.push_back("2".to_string(), token);
+
+            list.clear(token);
+
+            assert!(list.is_empty());
+            assert_eq!(0, list.len());
+        });
+    }
+
+    #[test]
+    fn ghost_debug() {
+        with_list(|list, token| {
+            assert_eq!("[]", format!("{:?}", WithToken::new(&*list, token)));
+
+            list.push_back("1".to_string(), token);
+            list.push_back("2".to_string(), token);
+
+            assert_eq!(r#"["1", "2"]"#, format!("{:?}", WithToken::new(&*list, token)));
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn ghost_serialize() {
+        with_list(|list, token| {
+            push_all(list, &["1", "2"], token);
+
+            assert_eq!(r#"["1","2"]"#, serde_json::to_string(&WithToken::new(&*list, token)).unwrap());
+        });
+    }
+
+    #[test]
+    fn ghost_clone() {
+        with_list(|list, token| {
+            push_all(list, &["1", "2", "3"], token);
+
+            let mut clone = list.ghost_clone(&mut GhostCloneContext::new(), token);
+
+            check_links(&clone, token);
+            assert_eq!(3, clone.len());
+            assert!(list.ghost_eq(&clone, token));
+
+            list.push_back("4".to_string(), token);
+            clone.front_mut(token).unwrap().push('0');
+
+            assert_eq!(vec!["1", "2", "3", "4"], collect(list, token));
+            assert_eq!(vec!["10", "2", "3"], collect(&clone, token));
+
+            clone.clear(token);
+        });
+    }
+
+    #[test]
+    fn ghost_eq_ord_hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash<'brand>(list: &GhostLinkedList<'brand, String>, token: &GhostToken<'brand>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            list.ghost_hash(&mut hasher, token);
+            hasher.finish()
+        }
+
+        with_list(|list, token| {
+            let mut other = GhostLinkedList::new();
+
+            assert!(list.ghost_eq(&other, token));
+
+            push_all(list, &["1", "2"], token);
+            push_all(&mut other, &["1", "2"], token);
+
+            assert!(list.ghost_eq(&other, token));
+            assert_eq!(Ordering::Equal, list.ghost_cmp(&other, token));
+            assert_eq!(hash(list, token), hash(&other, token));
+
+            other.push_back("0".to_string(), token);
+
+            assert!(list.ghost_ne(&other, token));
+            assert_eq!(Some(Ordering::Less), list.ghost_partial_cmp(&other, token));
+            assert_ne!(hash(list, token), hash(&other, token));
+
+            assert!(WithToken::new(&*list, token) < WithToken::new(&other, token));
+
+            other.clear(token);
+        });
+    }
+
+    #[test]
+    fn seal() {
+        use crate::GhostSea;
+
+        let sea = GhostToken::new(|mut token| {
+            let mut list = GhostLinkedList::new();
+
+            push_all(&mut list, &["1", "2", "3"], &mut token);
+
+            GhostSea::seal(list, token)
+        });
+
+        let result = sea.apply_once(|mut list, mut token| {
+            list.push_back("4".to_string(), &mut token);
+
+            let result = collect(&list, &token);
+
+            list.clear(&mut token);
+
+            result
+        });
+
+        assert_eq!(vec!["1", "2", "3", "4"], result);
+    }
+
+    #[test]
+    fn map() {
+        use crate::GhostSea;
+
+        let sea = GhostToken::new(|mut token| {
+            let mut list = GhostLinkedList::new();
+
+            push_all(&mut list, &["1", "2", "3"], &mut token);
+
+            GhostSea::seal(list, token)
+        });
+
+        let sea: GhostSea<Vec<GhostCell<'static, String>>> = sea.map(|mut list, token| {
+            let mut cells = Vec::new();
+
+            while let Some(element) = list.pop_front(token) {
+                cells.push(GhostCell::new(element));
+            }
+
+            cells
+        });
+
+        let result: Vec<String> =
+            sea.apply_ref(|cells, token| cells.iter().map(|cell| cell.borrow(token).clone()).collect());
+
+        assert_eq!(vec!["1", "2", "3"], result);
+    }
+
+    #[test]
+    fn cursor() {
+        with_list(|list, token| {
+            for i in 0..3 {
+                list.push_back(i.to_string(), token);
+            }
+
+            let mut cursor = list.cursor_front(token);
+
+            assert_eq!(Some("0"), cursor.current().map(|s| &**s));
+            assert_eq!(None, cursor.peek_prev());
+            assert_eq!(Some("1"), cursor.peek_next().map(|s| &**s));
+
+            cursor.move_next();
+            cursor.move_next();
+
+            assert_eq!(Some("2"), cursor.current().map(|s| &**s));
+
+            cursor.move_next();
+
+            assert_eq!(None, cursor.current());
+            assert_eq!(Some("0"), cursor.peek_next().map(|s| &**s));
+            assert_eq!(Some("2"), cursor.peek_prev().map(|s| &**s));
+
+            cursor.move_prev();
+
+            assert_eq!(Some("2"), cursor.current().map(|s| &**s));
+            assert_eq!(Some("0"), cursor.front().map(|s| &**s));
+            assert_eq!(Some("2"), cursor.back().map(|s| &**s));
+        });
+    }
+
+    #[test]
+    fn cursor_mut_insert_remove() {
+        with_list(|list, token| {
+            let mut cursor = list.cursor_front_mut(token);
+
+            cursor.insert_after("1".to_string());
+            cursor.insert_before("3".to_string());
+            cursor.insert_after("0".to_string());
+
+            cursor.move_next();
+            cursor.insert_after("2".to_string());
+            cursor.current().unwrap().push('!');
+
+            assert_eq!(Some("0!"), cursor.as_cursor().current().map(|s| &**s));
+            assert_eq!(Some("2".to_string()), cursor.peek_next().cloned());
+
+            cursor.move_next();
+
+            assert_eq!(Some("2".to_string()), cursor.remove_current());
+            assert_eq!(Some("1"), cursor.current().map(|s| &**s));
+
+            cursor.move_next();
+            cursor.move_next();
+
+            assert_eq!(None, cursor.remove_current());
+
+            assert_eq!(vec!["0!", "1", "3"], collect(list, token));
+
+            let reversed: Vec<_> = list.iter(token).rev().cloned().collect();
+
+            assert_eq!(vec!["3", "1", "0!"], reversed);
+
+            let mut cursor = list.cursor_back_mut(token);
+
+            while cursor.remove_current().is_some() {
+                cursor.move_prev();
+            }
+
+            assert!(list.is_empty());
+        });
+    }
+
+    #[test]
+    fn cursor_mut_splice() {
+        with_list(|list, token| {
+            list.push_back("1".to_string(), token);
+            list.push_back("4".to_string(), token);
+
+            let mut other = GhostLinkedList::new();
+            other.push_back("2".to_string(), token);
+            other.push_back("3".to_string(), token);
+
+            let mut front = GhostLinkedList::new();
+            front.push_back("0".to_string(), token);
+
+            let mut back = GhostLinkedList::new();
+            back.push_back("5".to_string(), token);
+
+            let mut cursor = list.cursor_front_mut(token);
+
+            cursor.splice_after(other);
+            cursor.splice_before(GhostLinkedList::new());
+            cursor.splice_before(front);
+
+            cursor.move_prev();
+            cursor.move_prev();
+
+            assert_eq!(None, cursor.current());
+
+            cursor.splice_before(back);
+
+            assert_eq!(vec!["0", "1", "2", "3", "4", "5"], collect(list, token));
+
+            let reversed: Vec<_> = list.iter(token).rev().cloned().collect();
+
+            assert_eq!(vec!["5", "4", "3", "2", "1", "0"], reversed);
+        });
+    }
+
+    #[test]
+    fn cursor_mut_split() {
+        with_list(|list, token| {
+            for i in 0..5 {
+                list.push_back(i.to_string(), token);
+            }
+
+            let mut cursor = list.cursor_front_mut(token);
+            cursor.move_next();
+
+            let mut before = cursor.split_before();
+
+            cursor.move_next();
+
+            let mut after = cursor.split_after();
+
+            assert_eq!(Some("2"), cursor.current().map(|s| &**s));
+
+            cursor.move_next();
+
+            let mut all = cursor.split_after();
+
+            assert_eq!(vec!["0"], collect(&before, token));
+            assert_eq!(vec!["3", "4"], collect(&after, token));
+            assert_eq!(vec!["1", "2"], collect(&all, token));
+            assert!(list.is_empty());
+
+            let mut cursor = all.cursor_back_mut(token);
+
+            assert!(cursor.split_after().is_empty());
+
+            let mut cursor = all.cursor_front_mut(token);
+
+            assert!(cursor.split_before().is_empty());
+
+            before.clear(token);
+            after.clear(token);
+            all.clear(token);
+        });
+    }
+
+    #[test]
+    fn append() {
+        with_list(|list, token| {
+            let mut other = GhostLinkedList::new();
+
+            list.append(&mut other, token);
+
+            assert!(list.is_empty());
+
+            other.push_back("1".to_string(), token);
+            list.append(&mut other, token);
+
+            assert!(other.is_empty());
+
+            other.push_back("2".to_string(), token);
+            other.push_back("3".to_string(), token);
+            list.append(&mut other, token);
+
+            assert!(other.is_empty());
+            assert_eq!(vec!["1", "2", "3"], collect(list, token));
+
+            let reversed: Vec<_> = list.iter(token).rev().cloned().collect();
+
+            assert_eq!(vec!["3", "2", "1"], reversed);
+        });
+    }
+
+    #[test]
+    fn split_off() {
+        with_list(|list, token| {
+            for i in 0..5 {
+                list.push_back(i.to_string(), token);
+            }
+
+            let mut empty = list.split_off(5, token);
+            let mut back = list.split_off(3, token);
+            let mut middle = list.split_off(1, token);
+
+            assert!(empty.is_empty());
+            assert_eq!(vec!["0"], collect(list, token));
+            assert_eq!(vec!["1", "2"], collect(&middle, token));
+            assert_eq!(vec!["3", "4"], collect(&back, token));
+
+            let mut all = list.split_off(0, token);
+
+            assert!(list.is_empty());
+            assert_eq!(vec!["0"], collect(&all, token));
+
+            for other in [&mut empty, &mut back, &mut middle, &mut all] {
+                other.clear(token);
+            }
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot split off at a nonexistent index")]
+    fn split_off_out_of_bounds() {
+        with_list(|list, token| {
+            list.split_off(1, token);
+        });
+    }
+
+    #[test]
+    fn cursor_mut_split_off() {
+        with_list(|list, token| {
+            for i in 0..3 {
+                list.push_back(i.to_string(), token);
+            }
+
+            let mut cursor = list.cursor_back_mut(token);
+            cursor.move_next();
+
+            assert!(cursor.split_off().is_empty());
+
+            cursor.move_prev();
+            cursor.move_prev();
+
+            let mut back = cursor.split_off();
+
+            assert_eq!(None, cursor.current());
+            assert_eq!(Some("0"), cursor.peek_next().map(|s| &**s));
+
+            assert_eq!(vec!["0"], collect(list, token));
+            assert_eq!(vec!["1", "2"], collect(&back, token));
+
+            back.clear(token);
+        });
+    }
+
+    #[test]
+    fn handle() {
+        with_list(|list, token| {
+            list.push_back("1".to_string(), token);
+
+            let zero = list.push_front_with_handle("0".to_string(), token);
+            let two = list.push_back_with_handle("2".to_string(), token);
+
+            assert_eq!(Some("0"), list.get(&zero, token).map(|s| &**s));
+
+            list.get_mut(&two, token).unwrap().push('!');
+
+            assert!(list.move_to_front(&two, token));
+            assert!(list.move_to_front(&two, token));
+
+            assert_eq!(vec!["2!", "0", "1"], collect(list, token));
+
+            assert_eq!(Some("0".to_string()), list.remove(zero, token));
+
+            assert_eq!(vec!["2!", "1"], collect(list, token));
+
+            let reversed: Vec<_> = list.iter(token).rev().cloned().collect();
+
+            assert_eq!(vec!["1", "2!"], reversed);
+
+            list.release(two, token);
+
+            assert_eq!(Some("2!".to_string()), list.pop_front(token));
+        });
+    }
+
+    #[test]
+    fn handle_removed() {
+        with_list(|list, token| {
+            let one = list.push_back_with_handle("1".to_string(), token);
+            let two = list.push_back_with_handle("2".to_string(), token);
+
+            let mut cursor = list.cursor_front_mut(token);
+
+            let zero = cursor.insert_before_with_handle("0".to_string());
+            let three = cursor.insert_after_with_handle("3".to_string());
+
+            assert_eq!(vec!["0", "1", "3", "2"], collect(list, token));
+
+            assert_eq!(Some("0".to_string()), list.pop_front(token));
+            assert_eq!(Some("2".to_string()), list.pop_back(token));
+
+            list.clear(token);
+
+            assert_eq!(None, list.get(&one, token));
+            assert_eq!(None, list.get_mut(&two, token));
+            assert!(!list.move_to_front(&three, token));
+
+            assert_eq!(None, list.remove(one, token));
+            assert_eq!(None, list.remove(two, token));
+
+            list.release(zero, token);
+            list.release(three, token);
+
+            assert!(list.is_empty());
+        });
+    }
+
+    #[test]
+    fn handle_other_list() {
+        GhostToken::new(|mut token| {
+            let (mut list, mut other) = (GhostLinkedList::new(), GhostLinkedList::new());
+
+            list.push_back(0, &mut token);
+
+            let handle = other.push_back_with_handle(1, &mut token);
+
+            let result = panic::catch_unwind(AssertUnwindSafe(|| list.move_to_front(&handle, &mut token)));
+
+            assert!(result.is_err());
+            assert_eq!(Some(1), other.remove(handle, &mut token));
+
+            list.clear(&mut token);
+        });
+    }
+
+    #[test]
+    fn handle_moved() {
+        with_list(|list, token| {
+            let zero = list.push_back_with_handle("0".to_string(), token);
+
+            let mut other = GhostLinkedList::new();
+
+            let one = other.push_back_with_handle("1".to_string(), token);
+            let two = other.push_back_with_handle("2".to_string(), token);
+
+            list.append(&mut other, token);
+
+            assert_eq!(Some("1"), list.get(&one, token).map(|s| &**s));
+
+            list.sort_by(|a, b| b.cmp(a), token);
+            list.rotate_left(1, token);
+
+            assert_eq!(vec!["1", "0", "2"], collect(list, token));
+            assert_eq!(Some("2"), list.get(&two, token).map(|s| &**s));
+
+            list.sort(token);
+
+            let mut back = list.split_off(2, token);
+
+            assert_eq!(Some("2".to_string()), back.remove(two, token));
+            assert_eq!(Some("1".to_string()), list.remove(one, token));
+
+            let mut cursor = back.cursor_front_mut(token);
+            cursor.splice_after(mem::take(list));
+
+            assert_eq!(Some("0".to_string()), back.remove(zero, token));
+            assert!(back.is_empty());
+        });
+    }
+
+    #[test]
+    fn handle_dropped() {
+        let element = Rc::new(());
+
+        GhostToken::new(|mut token| {
+            let mut list = GhostLinkedList::new();
+
+            let handle = list.push_back_with_handle(Rc::clone(&element), &mut token);
+
+            mem::drop(list.push_back_with_handle(Rc::clone(&element), &mut token));
+            mem::drop(list.pop_front(&mut token));
+            mem::drop(handle);
+
+            assert_eq!(1, list.len());
+
+            list.clear(&mut token);
+        });
+
+        assert_eq!(1, Rc::strong_count(&element));
+    }
+
+    #[test]
+    fn len() {
+        fn check<'brand>(list: &GhostLinkedList<'brand, String>, token: &GhostToken<'brand>) {
+            assert_eq!(list.iter(token).count(), list.len());
+            assert_eq!(list.iter(token).rev().count(), list.len());
+        }
+
+        with_list(|list, token| {
+            for i in 0..6 {
+                list.push_back(i.to_string(), token);
+            }
+
+            let mut other = GhostLinkedList::new();
+            other.push_back("6".to_string(), token);
+
+            list.append(&mut other, token);
+
+            assert_eq!(0, other.len());
+            assert_eq!(7, list.len());
+            check(list, token);
+
+            let mut back = list.split_off(5, token);
+
+            assert_eq!(2, back.len());
+            check(&back, token);
+
+            let rest = back.split_off(1, token);
+
+            let mut cursor = list.cursor_front_mut(token);
+            cursor.move_next();
+            cursor.insert_before("a".to_string());
+
+            assert_eq!(Some(2), cursor.index());
+
+            cursor.splice_before(rest);
+
+            assert_eq!(Some(3), cursor.index());
+
+            let mut before = cursor.split_before();
+
+            assert_eq!(Some(0), cursor.index());
+            assert_eq!(3, before.len());
+
+            cursor.move_next();
+            cursor.remove_current();
+
+            assert_eq!(Some(1), cursor.index());
+
+            let mut after = cursor.split_after();
+
+            assert_eq!(Some(1), cursor.index());
+            assert_eq!(1, after.len());
+
+            assert_eq!(2, list.len());
+
+            check(list, token);
+            check(&before, token);
+            check(&after, token);
+
+            for other in [&mut back, &mut before, &mut after] {
+                other.clear(token);
+
+                assert_eq!(0, other.len());
+            }
+        });
+    }
+
+    #[test]
+    fn iter_split_off_append() {
+        fn check<'brand>(list: &mut GhostLinkedList<'brand, String>, expected: &[&str], token: &mut GhostToken<'brand>) {
+            let reversed: Vec<_> = expected.iter().rev().copied().collect();
+
+            assert_eq!(expected, collect(list, token));
+            assert_eq!(reversed, list.iter(token).rev().map(|s| &**s).collect::<Vec<_>>());
+            assert_eq!(expected, list.iter_mut(token).map(|s| &**s).collect::<Vec<_>>());
+            assert_eq!(reversed, list.iter_mut(token).rev().map(|s| &**s).collect::<Vec<_>>());
+
+            let mut iter = list.iter(token);
+
+            for (i, element) in expected.iter().enumerate() {
+                assert_eq!((expected.len() - i, Some(expected.len() - i)), iter.size_hint());
+                assert_eq!(Some(*element), iter.next().map(|s| &**s));
+            }
+
+            assert_eq!((0, Some(0)), iter.size_hint());
+            assert_eq!(None, iter.next());
+            assert_eq!(None, iter.next_back());
+        }
+
+        with_list(|list, token| {
+            push_all(list, &["0", "1", "2", "3", "4"], token);
+
+            let mut back = list.split_off(2, token);
+
+            check(list, &["0", "1"], token);
+            check(&mut back, &["2", "3", "4"], token);
+
+            let mut iter = back.iter_mut(token);
+
+            assert_eq!(Some("2"), iter.next().map(|s| &**s));
+            assert_eq!(Some("4"), iter.next_back().map(|s| &**s));
+            assert_eq!(Some("3"), iter.next().map(|s| &**s));
+            assert_eq!(None, iter.next_back());
+
+            list.append(&mut back, token);
+
+            check(list, &["0", "1", "2", "3", "4"], token);
+            check(&mut back, &[], token);
+
+            let mut iter = list.iter(token);
+
+            assert_eq!(Some("0"), iter.next().map(|s| &**s));
+            assert_eq!(Some("4"), iter.next_back().map(|s| &**s));
+            assert_eq!(3, iter.len());
+            assert_eq!(vec!["1", "2", "3"], iter.collect::<Vec<_>>());
+        });
+    }
+
+    #[test]
+    fn cursor_index() {
+        with_list(|list, token| {
+            assert_eq!(None, list.cursor_front(token).index());
+            assert_eq!(None, list.cursor_back_mut(token).index());
+
+            for i in 0..3 {
+                list.push_back(i.to_string(), token);
+            }
+
+            let mut cursor = list.cursor_back(token);
+
+            assert_eq!(Some(2), cursor.index());
+
+            cursor.move_next();
+
+            assert_eq!(None, cursor.index());
+
+            cursor.move_next();
+
+            assert_eq!(Some(0), cursor.index());
+
+            cursor.move_prev();
+            cursor.move_prev();
+
+            assert_eq!(Some(2), cursor.index());
+
+            let mut cursor = list.cursor_front_mut(token);
+            cursor.move_prev();
+            cursor.move_prev();
+
+            assert_eq!(Some(2), cursor.index());
+            assert_eq!(Some(2), cursor.as_cursor().index());
+        });
+    }
+
+    fn push_all<'brand>(list: &mut GhostLinkedList<'brand, String>, elements: &[&str], token: &mut GhostToken<'brand>) {
+        for element in elements {
+            list.push_back(element.to_string(), token);
+        }
+    }
+
+    fn check_links<'brand>(list: &GhostLinkedList<'brand, String>, token: &GhostToken<'brand>) {
+        let mut reversed: Vec<_> = list.iter(token).rev().cloned().collect();
+        reversed.reverse();
+
+        assert_eq!(collect(list, token), reversed);
+        assert_eq!(list.len(), reversed.len());
+    }
+
+    #[test]
+    fn sort() {
+        with_list(|list, token| {
+            list.sort(token);
+
+            assert!(list.is_empty());
+
+            push_all(list, &["5", "3", "9", "1", "4", "8", "2", "7", "6", "0", "3"], token);
+
+            list.sort(token);
+
+            assert_eq!(vec!["0", "1", "2", "3", "3", "4", "5", "6", "7", "8", "9"], collect(list, token));
+            check_links(list, token);
+
+            list.sort_by(|a, b| b.cmp(a), token);
+
+            assert_eq!(vec!["9", "8", "7", "6", "5", "4", "3", "3", "2", "1", "0"], collect(list, token));
+            check_links(list, token);
+        });
+    }
+
+    #[test]
+    fn sort_stable() {
+        with_list(|list, token| {
+            push_all(list, &["b1", "a1", "c1", "b2", "a2", "c2", "a3"], token);
+
+            list.sort_by_key(|s| s.as_bytes()[0], token);
+
+            assert_eq!(vec!["a1", "a2", "a3", "b1", "b2", "c1", "c2"], collect(list, token));
+            check_links(list, token);
+        });
+    }
+
+    #[test]
+    fn sort_panicking() {
+        with_list(|list, token| {
+            push_all(list, &["3", "1", "2", "0", "4"], token);
+
+            let mut count = 0;
+
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                list.sort_by(|a, b| {
+                    count += 1;
+                    assert!(count < 4, "Panicking compare");
+                    a.cmp(b)
+                }, token)
+            }));
+
+            assert!(result.is_err());
+
+            let mut elements = collect(list, token);
+            elements.sort();
+
+            assert_eq!(vec!["0", "1", "2", "3", "4"], elements);
+            check_links(list, token);
+        });
+    }
+
+    #[test]
+    fn reverse() {
+        with_list(|list, token| {
+            list.reverse(token);
+
+            push_all(list, &["0"], token);
+            list.reverse(token);
+
+            assert_eq!(vec!["0"], collect(list, token));
+
+            push_all(list, &["1", "2", "3"], token);
+            list.reverse(token);
+
+            assert_eq!(vec!["3", "2", "1", "0"], collect(list, token));
+            check_links(list, token);
+        });
+    }
+
+    #[test]
+    fn dedup() {
+        with_list(|list, token| {
+            push_all(list, &["a", "a", "b", "c", "c", "c", "a", "d", "d"], token);
+
+            list.dedup(token);
+
+            assert_eq!(vec!["a", "b", "c", "a", "d"], collect(list, token));
+            check_links(list, token);
+
+            list.dedup_by(|a, b| { b.push_str(a); true }, token);
+
+            assert_eq!(vec!["abcad"], collect(list, token));
+            check_links(list, token);
+        });
+    }
+
+    #[test]
+    fn rotate() {
+        with_list(|list, token| {
+            list.rotate_left(0, token);
+
+            push_all(list, &["0", "1", "2", "3", "4"], token);
+
+            list.rotate_left(2, token);
+
+            assert_eq!(vec!["2", "3", "4", "0", "1"], collect(list, token));
+
+            list.rotate_right(1, token);
+
+            assert_eq!(vec!["1", "2", "3", "4", "0"], collect(list, token));
+
+            list.rotate_left(5, token);
+            list.rotate_right(0, token);
+
+            assert_eq!(vec!["1", "2", "3", "4", "0"], collect(list, token));
+            check_links(list, token);
+        });
+    }
+
+    #[test]
+    fn retain() {
+        with_list(|list, token| {
+            push_all(list, &["0", "1", "2", "3", "4", "5"], token);
+
+            list.retain(|s| s != "0" && s != "3" && s != "5", token);
+
+            assert_eq!(vec!["1", "2", "4"], collect(list, token));
+
+            list.retain_mut(|s| { s.push('!'); s != "2!" }, token);
+
+            assert_eq!(vec!["1!", "4!"], collect(list, token));
+            check_links(list, token);
+        });
+    }
+
+    #[test]
+    fn extract_if() {
+        with_list(|list, token| {
+            push_all(list, &["0", "1", "2", "3", "4", "5"], token);
+
+            {
+                let mut extracted = list.extract_if(|s| s.parse::<u32>().unwrap() % 2 == 0, token);
+
+                assert_eq!((0, Some(6)), extracted.size_hint());
+                assert_eq!(Some("0".to_string()), extracted.next());
+                assert_eq!(Some("2".to_string()), extracted.next());
+                assert_eq!((0, Some(3)), extracted.size_hint());
+            }
+
+            assert_eq!(vec!["1", "3", "4", "5"], collect(list, token));
+
+            let extracted: Vec<_> = list.extract_if(|_| true, token).collect();
+
+            assert_eq!(vec!["1", "3", "4", "5"], extracted);
+            assert!(list.is_empty());
+        });
+    }
+}
@@ -0,0 +1,1173 @@
+//  A linked-list implemented atop `GhostLinkedList`.
+//
+//  The only unsafe code is the use of `GhostDropSea::parts` and `GhostDropSea::parts_mut` to hand out iterators and
+//  cursors borrowing the list, see the `LinkedList` docs.
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::{FromIterator, FusedIterator},
+    mem,
+};
+
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::GhostDropSea;
+
+use super::{
+    GhostLinkedList, GhostLinkedListCursor, GhostLinkedListCursorMut, GhostLinkedListExtractIf,
+    GhostLinkedListIterator, GhostLinkedListIteratorMut,
+};
+
+/// A typically self-sufficient linked list.
+///
+/// The list is safe to use, yet it is not written in entirely safe code: `iter`, `iter_mut`, the cursors, `split_off`
+/// and `extract_if` borrow the list and its `'static` token through `unsafe { self.0.parts() }` or `parts_mut()`, as
+/// the iterators and cursors they return could not outlive a closure passed to `GhostDropSea::apply_ref`.
+///
+/// This is sound as the `'static` token never escapes the facade types: it is only ever stored within `LinkedList` and
+/// the iterators and cursors wrapping it, none of which expose it, hence it cannot be mixed with the nodes of another
+/// list sharing the `'static` brand.
+pub struct LinkedList<T>(GhostDropSea<Ghost<T>>);
+
+impl<T> LinkedList<T> {
+    /// Creates an empty instance.
+    pub fn new() -> Self { Self(GhostDropSea::default()) }
+
+    /// Creates an iterator over self.
+    pub fn iter(&self) -> LinkedListIterator<'_, T> {
+        //  Safety:
+        //  -   The token is only used to iterate over the nodes of this list, and does not escape.
+        let (ghost, token) = unsafe { self.0.parts() };
+
+        LinkedListIterator(ghost.iter(token))
+    }
+
+    /// Creates a mutable iterator over self.
+    pub fn iter_mut(&mut self) -> LinkedListIteratorMut<'_, T> {
+        //  Safety:
+        //  -   The token is only used to iterate over the nodes of this list, and does not escape.
+        let (ghost, token) = unsafe { self.0.parts_mut() };
+
+        LinkedListIteratorMut(ghost.iter_mut(token))
+    }
+
+    /// Creates a cursor pointing to the front element, or the "ghost" element if empty.
+    pub fn cursor_front(&self) -> LinkedListCursor<'_, T> {
+        //  Safety:
+        //  -   The token is only used to navigate the nodes of this list, and does not escape.
+        let (ghost, token) = unsafe { self.0.parts() };
+
+        LinkedListCursor(ghost.cursor_front(token))
+    }
+
+    /// Creates a cursor pointing to the back element, or the "ghost" element if empty.
+    pub fn cursor_back(&self) -> LinkedListCursor<'_, T> {
+        //  Safety:
+        //  -   The token is only used to navigate the nodes of this list, and does not escape.
+        let (ghost, token) = unsafe { self.0.parts() };
+
+        LinkedListCursor(ghost.cursor_back(token))
+    }
+
+    /// Creates a mutable cursor pointing to the front element, or the "ghost" element if empty.
+    pub fn cursor_front_mut(&mut self) -> LinkedListCursorMut<'_, T> {
+        //  Safety:
+        //  -   The token is only used to navigate and edit the nodes of this list, and does not escape.
+        let (ghost, token) = unsafe { self.0.parts_mut() };
+
+        LinkedListCursorMut(ghost.cursor_front_mut(token))
+    }
+
+    /// Creates a mutable cursor pointing to the back element, or the "ghost" element if empty.
+    pub fn cursor_back_mut(&mut self) -> LinkedListCursorMut<'_, T> {
+        //  Safety:
+        //  -   The token is only used to navigate and edit the nodes of this list, and does not escape.
+        let (ghost, token) = unsafe { self.0.parts_mut() };
+
+        LinkedListCursorMut(ghost.cursor_back_mut(token))
+    }
+
+    /// Moves all elements of `other` to the back of the list, leaving `other` empty.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1)
+    pub fn append(&mut self, other: &mut Self) {
+        let other = mem::take(other).0.into_sea();
+
+        self.0.combine_mut(other, |ghost, mut other, token| ghost.append(&mut other, token));
+    }
+
+    /// Splits the list in two at the given index, returning the elements from `at` onwards.
+    ///
+    /// #   Panics
+    ///
+    /// If `at > len`.
+    ///
+    /// #   Complexity
+    ///
+    /// O(min(at, len - at)), to walk from the closest end.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        //  Safety:
+        //  -   The token is only used to split the nodes of this list, and does not escape.
+        let (ghost, token) = unsafe { self.0.parts_mut() };
+
+        Self(GhostDropSea::new(ghost.split_off(at, token)))
+    }
+
+    /// Splits the list in two at the current element of `cursor`, returning it and the elements after it.
+    ///
+    /// If the cursor points to the "ghost" element, an empty list is returned. See `LinkedListCursorMut::split_off`.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1)
+    pub fn split_off_cursor(mut cursor: LinkedListCursorMut<'_, T>) -> Self { cursor.split_off() }
+
+    /// Returns whether the list is empty.
+    pub fn is_empty(&self) -> bool { self.0.apply_ref(|ghost, _| ghost.is_empty()) }
+
+    /// Returns the length of the list.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1)
+    pub fn len(&self) -> usize { self.0.apply_ref(|ghost, _| ghost.len()) }
+
+    /// Clears the list.
+    pub fn clear(&mut self) { self.0.apply_mut(|ghost, token| ghost.clear(token)) }
+
+    /// Pushes an item at the front of the list.
+    pub fn push_front(&mut self, value: T) { self.0.apply_mut(|ghost, token| ghost.push_front(value, token)) }
+
+    /// Pushes an item at the back of the list.
+    pub fn push_back(&mut self, value: T) { self.0.apply_mut(|ghost, token| ghost.push_back(value, token)) }
+
+    /// Returns the front item, if any.
+    pub fn front(&self) -> Option<&T> { self.0.apply_ref(|ghost, token| ghost.front(token)) }
+
+    /// Returns the front item, if any.
+    pub fn front_mut(&mut self) -> Option<&mut T> { self.0.apply_mut(|ghost, token| ghost.front_mut(token)) }
+
+    /// Returns the back item, if any.
+    pub fn back(&self) -> Option<&T> { self.0.apply_ref(|ghost, token| ghost.back(token)) }
+
+    /// Returns the back item, if any.
+    pub fn back_mut(&mut self) -> Option<&mut T> { self.0.apply_mut(|ghost, token| ghost.back_mut(token)) }
+
+    /// Pops the front item of the list, if any.
+    pub fn pop_front(&mut self) -> Option<T> { self.0.apply_mut(|ghost, token| ghost.pop_front(token)) }
+
+    /// Pops the back item of the list, if any.
+    pub fn pop_back(&mut self) -> Option<T> { self.0.apply_mut(|ghost, token| ghost.pop_back(token)) }
+
+    /// Sorts the list, preserving the order of equal elements.
+    ///
+    /// The nodes are relinked in place, without allocating.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.0.apply_mut(|ghost, token| ghost.sort(token))
+    }
+
+    /// Sorts the list with a comparator function, preserving the order of equal elements.
+    ///
+    /// The nodes are relinked in place, without allocating. Should `compare` panic, all elements remain in the list, in
+    /// an unspecified order.
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.0.apply_mut(|ghost, token| ghost.sort_by(compare, token))
+    }
+
+    /// Sorts the list with a key extraction function, preserving the order of equal elements.
+    ///
+    /// The nodes are relinked in place, without allocating.
+    pub fn sort_by_key<K, F>(&mut self, fun: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.0.apply_mut(|ghost, token| ghost.sort_by_key(fun, token))
+    }
+
+    /// Reverses the order of the elements of the list.
+    pub fn reverse(&mut self) { self.0.apply_mut(|ghost, token| ghost.reverse(token)) }
+
+    /// Removes consecutive elements which are equal, keeping the first of each run.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.0.apply_mut(|ghost, token| ghost.dedup(token))
+    }
+
+    /// Removes consecutive elements which resolve to the same key, keeping the first of each run.
+    pub fn dedup_by_key<K, F>(&mut self, key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.0.apply_mut(|ghost, token| ghost.dedup_by_key(key, token))
+    }
+
+    /// Removes consecutive elements satisfying the given equality relation, keeping the first of each run.
+    ///
+    /// As with `Vec::dedup_by`, `same_bucket(a, b)` is passed the element under consideration as `a`, and the
+    /// preceding element which was kept as `b`.
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.0.apply_mut(|ghost, token| ghost.dedup_by(same_bucket, token))
+    }
+
+    /// Rotates the list `n` places to the left: the first `n` elements are moved to the back.
+    ///
+    /// #   Panics
+    ///
+    /// If `n > len`.
+    pub fn rotate_left(&mut self, n: usize) { self.0.apply_mut(|ghost, token| ghost.rotate_left(n, token)) }
+
+    /// Rotates the list `n` places to the right: the last `n` elements are moved to the front.
+    ///
+    /// #   Panics
+    ///
+    /// If `n > len`.
+    pub fn rotate_right(&mut self, n: usize) { self.0.apply_mut(|ghost, token| ghost.rotate_right(n, token)) }
+
+    /// Retains only the elements satisfying the predicate.
+    pub fn retain<F>(&mut self, fun: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.0.apply_mut(|ghost, token| ghost.retain(fun, token))
+    }
+
+    /// Retains only the elements satisfying the predicate, which may modify them.
+    pub fn retain_mut<F>(&mut self, fun: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.0.apply_mut(|ghost, token| ghost.retain_mut(fun, token))
+    }
+
+    /// Creates an iterator which removes, and yields, the elements satisfying the predicate.
+    ///
+    /// Elements which are not yielded, either because they do not satisfy the predicate or because the iterator is
+    /// dropped before reaching them, remain in the list.
+    pub fn extract_if<F>(&mut self, filter: F) -> LinkedListExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        //  Safety:
+        //  -   The token is only used to navigate and edit the nodes of this list, and does not escape.
+        let (ghost, token) = unsafe { self.0.parts_mut() };
+
+        LinkedListExtractIf(ghost.extract_if(filter, token))
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self { self.0.apply_ref(|ghost, token| ghost.iter(token).cloned().collect()) }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.apply_ref(|ghost, token| f.debug_list().entries(ghost.iter(token)).finish())
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.apply_ref(|ghost, token| {
+            other.0.apply_ref(|other, other_token| {
+                ghost.len() == other.len() && ghost.iter(token).eq(other.iter(other_token))
+            })
+        })
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.apply_ref(|ghost, token| {
+            other.0.apply_ref(|other, other_token| ghost.iter(token).partial_cmp(other.iter(other_token)))
+        })
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.apply_ref(|ghost, token| {
+            other.0.apply_ref(|other, other_token| ghost.iter(token).cmp(other.iter(other_token)))
+        })
+    }
+}
+
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.apply_ref(|ghost, token| {
+            ghost.len().hash(state);
+            ghost.iter(token).for_each(|element| element.hash(state));
+        })
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.apply_mut(|ghost, token| iter.into_iter().for_each(|element| ghost.push_back(element, token)))
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) { self.extend(iter.into_iter().copied()) }
+}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    fn from(array: [T; N]) -> Self { IntoIterator::into_iter(array).collect() }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<std::collections::LinkedList<T>> for LinkedList<T> {
+    fn from(list: std::collections::LinkedList<T>) -> Self { list.into_iter().collect() }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<LinkedList<T>> for std::collections::LinkedList<T> {
+    fn from(list: LinkedList<T>) -> Self { list.into_iter().collect() }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<std::collections::VecDeque<T>> for LinkedList<T> {
+    fn from(deque: std::collections::VecDeque<T>) -> Self { deque.into_iter().collect() }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<LinkedList<T>> for std::collections::VecDeque<T> {
+    fn from(list: LinkedList<T>) -> Self { list.into_iter().collect() }
+}
+
+//  Serialized as a sequence of its elements.
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for LinkedList<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { serializer.collect_seq(self) }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for LinkedList<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> de::Visitor<'de> for Visitor<T> {
+            type Value = LinkedList<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("a sequence") }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut list = LinkedList::new();
+
+                while let Some(value) = seq.next_element()? {
+                    list.push_back(value);
+                }
+
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(Visitor(PhantomData))
+    }
+}
+
+//  Deserialized through `LinkedList`, which drops the elements deserialized so far on failure, for use within a
+//  `GhostSea`.
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for GhostLinkedList<'static, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        LinkedList::deserialize(deserializer).map(|list| list.0.into_inner())
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = LinkedListIntoIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+
+        LinkedListIntoIterator { list: self, len, }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = LinkedListIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = LinkedListIteratorMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+/// An iterator over a LinkedList.
+pub struct LinkedListIterator<'a, T>(GhostLinkedListIterator<'a, 'static, T>);
+
+impl<'a, T> Clone for LinkedListIterator<'a, T> {
+    fn clone(&self) -> Self { Self(self.0.clone()) }
+}
+
+impl<'a, T> Iterator for LinkedListIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> { self.0.next() }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.0.size_hint() }
+}
+
+impl<'a, T> DoubleEndedIterator for LinkedListIterator<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> { self.0.next_back() }
+}
+
+impl<'a, T> ExactSizeIterator for LinkedListIterator<'a, T> {}
+
+impl<'a, T> FusedIterator for LinkedListIterator<'a, T> {}
+
+/// A mutable iterator over a LinkedList.
+pub struct LinkedListIteratorMut<'a, T>(GhostLinkedListIteratorMut<'a, 'static, T>);
+
+impl<'a, T> Iterator for LinkedListIteratorMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> { self.0.next() }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.0.size_hint() }
+}
+
+impl<'a, T> DoubleEndedIterator for LinkedListIteratorMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> { self.0.next_back() }
+}
+
+impl<'a, T> ExactSizeIterator for LinkedListIteratorMut<'a, T> {}
+
+impl<'a, T> FusedIterator for LinkedListIteratorMut<'a, T> {}
+
+/// A consuming iterator over a LinkedList.
+pub struct LinkedListIntoIterator<T> {
+    list: LinkedList<T>,
+    len: usize,
+}
+
+impl<T> Iterator for LinkedListIntoIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.list.pop_front();
+        self.len -= result.is_some() as usize;
+        result
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.len, Some(self.len)) }
+}
+
+impl<T> DoubleEndedIterator for LinkedListIntoIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let result = self.list.pop_back();
+        self.len -= result.is_some() as usize;
+        result
+    }
+}
+
+impl<T> ExactSizeIterator for LinkedListIntoIterator<T> {}
+
+impl<T> FusedIterator for LinkedListIntoIterator<T> {}
+
+/// An iterator removing, and yielding, the elements of a LinkedList satisfying a predicate.
+///
+/// Created by `LinkedList::extract_if`.
+pub struct LinkedListExtractIf<'a, T, F>(GhostLinkedListExtractIf<'a, 'static, T, F>);
+
+impl<'a, T, F> Iterator for LinkedListExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> { self.0.next() }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.0.size_hint() }
+}
+
+impl<'a, T, F> FusedIterator for LinkedListExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{}
+
+/// A cursor over a LinkedList.
+///
+/// The cursor points either to an element, or to the "ghost" non-element between the back and the front of the list.
+pub struct LinkedListCursor<'a, T>(GhostLinkedListCursor<'a, 'static, T>);
+
+impl<'a, T> LinkedListCursor<'a, T> {
+    /// Returns the index of the current element, or `None` if at the "ghost" element.
+    pub fn index(&self) -> Option<usize> { self.0.index() }
+
+    /// Moves to the next element, or the "ghost" element if at the back; moves to the front if at the "ghost" element.
+    pub fn move_next(&mut self) { self.0.move_next() }
+
+    /// Moves to the previous element, or the "ghost" element if at the front; moves to the back if at the "ghost"
+    /// element.
+    pub fn move_prev(&mut self) { self.0.move_prev() }
+
+    /// Returns the current element, if any.
+    pub fn current(&self) -> Option<&'a T> { self.0.current() }
+
+    /// Returns the next element, if any.
+    pub fn peek_next(&self) -> Option<&'a T> { self.0.peek_next() }
+
+    /// Returns the previous element, if any.
+    pub fn peek_prev(&self) -> Option<&'a T> { self.0.peek_prev() }
+
+    /// Returns the front element of the list, if any.
+    pub fn front(&self) -> Option<&'a T> { self.0.front() }
+
+    /// Returns the back element of the list, if any.
+    pub fn back(&self) -> Option<&'a T> { self.0.back() }
+}
+
+impl<'a, T> Clone for LinkedListCursor<'a, T> {
+    fn clone(&self) -> Self { Self(self.0.clone()) }
+}
+
+/// A mutable cursor over a LinkedList.
+///
+/// The cursor points either to an element, or to the "ghost" non-element between the back and the front of the list.
+pub struct LinkedListCursorMut<'a, T>(GhostLinkedListCursorMut<'a, 'static, T>);
+
+impl<'a, T> LinkedListCursorMut<'a, T> {
+    /// Returns the index of the current element, or `None` if at the "ghost" element.
+    pub fn index(&self) -> Option<usize> { self.0.index() }
+
+    /// Moves to the next element, or the "ghost" element if at the back; moves to the front if at the "ghost" element.
+    pub fn move_next(&mut self) { self.0.move_next() }
+
+    /// Moves to the previous element, or the "ghost" element if at the front; moves to the back if at the "ghost"
+    /// element.
+    pub fn move_prev(&mut self) { self.0.move_prev() }
+
+    /// Returns the current element, if any.
+    pub fn current(&mut self) -> Option<&mut T> { self.0.current() }
+
+    /// Returns the next element, if any.
+    pub fn peek_next(&mut self) -> Option<&mut T> { self.0.peek_next() }
+
+    /// Returns the previous element, if any.
+    pub fn peek_prev(&mut self) -> Option<&mut T> { self.0.peek_prev() }
+
+    /// Returns a read-only cursor pointing to the current element, borrowing this cursor.
+    pub fn as_cursor(&self) -> LinkedListCursor<'_, T> { LinkedListCursor(self.0.as_cursor()) }
+
+    /// Inserts an element after the current one; at the front if the cursor points to the "ghost" element.
+    pub fn insert_after(&mut self, value: T) { self.0.insert_after(value) }
+
+    /// Inserts an element before the current one; at the back if the cursor points to the "ghost" element.
+    pub fn insert_before(&mut self, value: T) { self.0.insert_before(value) }
+
+    /// Removes the current element, if any, and moves to the next one.
+    pub fn remove_current(&mut self) -> Option<T> { self.0.remove_current() }
+
+    /// Inserts the elements of `list` after the current one; at the front if the cursor points to the "ghost" element.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1)
+    pub fn splice_after(&mut self, list: LinkedList<T>) { self.0.splice_after(list.0.into_inner()) }
+
+    /// Inserts the elements of `list` before the current one; at the back if the cursor points to the "ghost" element.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1)
+    pub fn splice_before(&mut self, list: LinkedList<T>) { self.0.splice_before(list.0.into_inner()) }
+
+    /// Splits the list at the current element, returning it and the elements after it, and moves to the "ghost"
+    /// element.
+    ///
+    /// If the cursor points to the "ghost" element, an empty list is returned.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1)
+    pub fn split_off(&mut self) -> LinkedList<T> { LinkedList(GhostDropSea::new(self.0.split_off())) }
+
+    /// Splits the list after the current element, returning the elements after it.
+    ///
+    /// If the cursor points to the "ghost" element, the whole list is returned.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1)
+    pub fn split_after(&mut self) -> LinkedList<T> { LinkedList(GhostDropSea::new(self.0.split_after())) }
+
+    /// Splits the list before the current element, returning the elements before it.
+    ///
+    /// If the cursor points to the "ghost" element, the whole list is returned.
+    ///
+    /// #   Complexity
+    ///
+    /// O(1)
+    pub fn split_before(&mut self) -> LinkedList<T> { LinkedList(GhostDropSea::new(self.0.split_before())) }
+}
+
+//
+//  Implementation
+//
+
+type Ghost<T> = GhostLinkedList<'static, T>;
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, mem, panic::{self, AssertUnwindSafe}};
+
+    use super::*;
+
+    //  Counts its drops, and panics on drop if so requested.
+    struct Counted<'a> {
+        drops: &'a Cell<usize>,
+        panics: bool,
+    }
+
+    impl<'a> Drop for Counted<'a> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+
+            if self.panics {
+                panic!("Counted::drop");
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let mut list: LinkedList<String> = LinkedList::default();
+
+        assert!(list.is_empty());
+        assert_eq!(0, list.len());
+
+        assert_eq!(None, list.front());
+        assert_eq!(None, list.back());
+        assert_eq!(None, list.front_mut());
+        assert_eq!(None, list.back_mut());
+        assert_eq!(None, list.pop_front());
+        assert_eq!(None, list.pop_back());
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut list = LinkedList::new();
+
+        list.push_back("2".to_string());
+        list.push_front("1".to_string());
+        list.push_back("3".to_string());
+
+        assert!(!list.is_empty());
+        assert_eq!(3, list.len());
+
+        assert_eq!(Some("1".to_string()), list.pop_front());
+        assert_eq!(Some("3".to_string()), list.pop_back());
+        assert_eq!(Some("2".to_string()), list.pop_front());
+        assert_eq!(None, list.pop_back());
+
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn front_back() {
+        let mut list = LinkedList::new();
+
+        list.push_back("Hello, World!".to_string());
+        list.push_back("Hello, You!".to_string());
+
+        assert_eq!(Some("Hello, World!"), list.front().map(|s| &**s));
+        assert_eq!(Some("Hello, You!"), list.back().map(|s| &**s));
+
+        list.front_mut().unwrap().make_ascii_uppercase();
+        list.back_mut().unwrap().make_ascii_lowercase();
+
+        assert_eq!(Some("HELLO, WORLD!"), list.front().map(|s| &**s));
+        assert_eq!(Some("hello, you!"), list.back().map(|s| &**s));
+
+        list.clear();
+    }
+
+    #[test]
+    fn clear() {
+        let mut list = LinkedList::new();
+
+        list.push_back(1);
+        list.push_back(2);
+
+        list.clear();
+
+        assert!(list.is_empty());
+        assert_eq!(0, list.len());
+
+        list.push_front(3);
+
+        assert_eq!(Some(&3), list.front());
+        assert_eq!(Some(3), list.pop_back());
+    }
+
+    #[test]
+    fn drop() {
+        let drops = Cell::new(0);
+
+        let mut list = LinkedList::new();
+
+        for _ in 0..4 {
+            list.push_back(Counted { drops: &drops, panics: false });
+        }
+
+        mem::drop(list);
+
+        assert_eq!(4, drops.get());
+    }
+
+    #[test]
+    fn drop_panicking() {
+        let drops = Cell::new(0);
+
+        let mut list = LinkedList::new();
+
+        for i in 0..5 {
+            list.push_back(Counted { drops: &drops, panics: i == 2 });
+        }
+
+        let result = panic::catch_unwind(AssertUnwindSafe(move || mem::drop(list)));
+
+        assert!(result.is_err());
+        assert_eq!(5, drops.get());
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = LinkedList::new();
+
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        let mut iter = list.iter();
+
+        assert_eq!(5, iter.len());
+        assert_eq!(Some(&0), iter.next());
+        assert_eq!(Some(&4), iter.next_back());
+        assert_eq!(Some(&1), iter.next());
+        assert_eq!(Some(&3), iter.next_back());
+        assert_eq!(1, iter.len());
+        assert_eq!(Some(&2), iter.next());
+        assert_eq!(None, iter.next_back());
+        assert_eq!(None, iter.next());
+
+        let mut sum = 0;
+
+        for i in &list {
+            sum += i;
+        }
+
+        assert_eq!(10, sum);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = LinkedList::new();
+
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        let mut iter = list.iter_mut();
+
+        let (first, last) = (iter.next().unwrap(), iter.next_back().unwrap());
+
+        *first += 10;
+        *last += 20;
+
+        assert_eq!(3, iter.len());
+
+        for i in iter {
+            *i *= 2;
+        }
+
+        for i in &mut list {
+            *i += 1;
+        }
+
+        assert_eq!(vec![11, 3, 5, 7, 25], list.iter().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn into_iter() {
+        let drops = Cell::new(0);
+
+        let mut list = LinkedList::new();
+
+        for _ in 0..5 {
+            list.push_back(Counted { drops: &drops, panics: false });
+        }
+
+        let mut iter = list.into_iter();
+
+        assert_eq!(5, iter.len());
+
+        iter.next();
+        iter.next_back();
+
+        assert_eq!(2, drops.get());
+        assert_eq!(3, iter.len());
+
+        mem::drop(iter);
+
+        assert_eq!(5, drops.get());
+    }
+
+    #[test]
+    fn cursor() {
+        let mut list = LinkedList::new();
+
+        for i in 0..3 {
+            list.push_back(i);
+        }
+
+        let mut cursor = list.cursor_back();
+
+        assert_eq!(Some(&2), cursor.current());
+        assert_eq!(Some(&1), cursor.peek_prev());
+
+        cursor.move_next();
+
+        assert_eq!(None, cursor.current());
+        assert_eq!(Some(&0), cursor.peek_next());
+
+        cursor.move_prev();
+        cursor.move_prev();
+
+        assert_eq!(Some(&1), cursor.current());
+        assert_eq!(Some(1), cursor.index());
+    }
+
+    #[test]
+    fn cursor_mut() {
+        let mut list = LinkedList::new();
+
+        for i in 0..3 {
+            list.push_back(i);
+        }
+
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.insert_before(10);
+        cursor.insert_after(11);
+        *cursor.current().unwrap() += 5;
+
+        cursor.move_next();
+
+        assert_eq!(Some(11), cursor.remove_current());
+        assert_eq!(Some(&1), cursor.as_cursor().current());
+
+        let mut other = LinkedList::new();
+        other.push_back(20);
+        other.push_back(21);
+
+        cursor.splice_after(other);
+
+        let after = cursor.split_after();
+        let before = cursor.split_before();
+
+        assert_eq!((1, Some(1)), list.iter().size_hint());
+        assert_eq!((2, Some(2)), before.iter().size_hint());
+        assert_eq!((3, Some(3)), after.iter().size_hint());
+
+        assert_eq!(vec![1], list.iter().copied().collect::<Vec<_>>());
+        assert_eq!(vec![10, 5], before.iter().copied().collect::<Vec<_>>());
+        assert_eq!(vec![20, 21, 2], after.iter().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn cursor_mut_drop() {
+        let drops = Cell::new(0);
+
+        let mut list = LinkedList::new();
+
+        for _ in 0..4 {
+            list.push_back(Counted { drops: &drops, panics: false });
+        }
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        let tail = cursor.split_after();
+
+        assert_eq!(0, drops.get());
+
+        mem::drop(tail);
+
+        assert_eq!(2, drops.get());
+
+        mem::drop(list);
+
+        assert_eq!(4, drops.get());
+    }
+
+    #[test]
+    fn append() {
+        let drops = Cell::new(0);
+
+        let (mut list, mut other) = (LinkedList::new(), LinkedList::new());
+
+        for _ in 0..2 {
+            list.push_back(Counted { drops: &drops, panics: false });
+            other.push_back(Counted { drops: &drops, panics: false });
+        }
+
+        list.append(&mut other);
+
+        assert!(other.is_empty());
+        assert_eq!(4, list.len());
+
+        mem::drop(other);
+
+        assert_eq!(0, drops.get());
+
+        mem::drop(list);
+
+        assert_eq!(4, drops.get());
+    }
+
+    #[test]
+    fn split_off() {
+        let mut list: LinkedList<_> = LinkedList::new();
+
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        let mut back = list.split_off(3);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        let middle = cursor.split_off();
+
+        assert_eq!(vec![0], list.iter().copied().collect::<Vec<_>>());
+        assert_eq!(vec![1, 2], middle.iter().copied().collect::<Vec<_>>());
+        assert_eq!(vec![3, 4], back.iter().copied().collect::<Vec<_>>());
+
+        let mut cursor = list.cursor_back_mut();
+        cursor.move_next();
+
+        assert!(LinkedList::split_off_cursor(cursor).is_empty());
+
+        let cursor = back.cursor_front_mut();
+
+        let three = LinkedList::split_off_cursor(cursor);
+
+        assert_eq!(vec![3, 4], three.iter().copied().collect::<Vec<_>>());
+        assert!(back.is_empty());
+
+        let mut rest = list.split_off(1);
+
+        assert!(rest.is_empty());
+
+        rest.append(&mut list);
+
+        assert!(list.is_empty());
+        assert_eq!(vec![0], rest.iter().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn sort_reverse() {
+        let mut list: LinkedList<_> = LinkedList::new();
+
+        for i in [3, 1, 4, 1, 5, 9, 2, 6] {
+            list.push_back(i);
+        }
+
+        list.sort();
+
+        assert_eq!(vec![1, 1, 2, 3, 4, 5, 6, 9], list.iter().copied().collect::<Vec<_>>());
+
+        list.sort_by_key(|i| i % 3);
+
+        assert_eq!(vec![3, 6, 9, 1, 1, 4, 2, 5], list.iter().copied().collect::<Vec<_>>());
+
+        list.sort_by(|a, b| b.cmp(a));
+        list.reverse();
+
+        assert_eq!(vec![1, 1, 2, 3, 4, 5, 6, 9], list.iter().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn dedup_rotate() {
+        let mut list: LinkedList<_> = LinkedList::new();
+
+        for i in [1, 1, 2, 3, 3, 4, 14] {
+            list.push_back(i);
+        }
+
+        list.dedup();
+        list.dedup_by_key(|i| *i % 10);
+
+        assert_eq!(vec![1, 2, 3, 4], list.iter().copied().collect::<Vec<_>>());
+
+        list.dedup_by(|a, b| *a == *b + 1);
+
+        assert_eq!(vec![1, 3], list.iter().copied().collect::<Vec<_>>());
+
+        list.push_back(5);
+        list.rotate_left(1);
+        list.rotate_right(1);
+
+        assert_eq!(vec![1, 3, 5], list.iter().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn retain_extract_if() {
+        let drops = Cell::new(0);
+
+        let mut list = LinkedList::new();
+
+        for _ in 0..6 {
+            list.push_back(Counted { drops: &drops, panics: false });
+        }
+
+        let mut index = 0;
+
+        list.retain(|_| { index += 1; index % 3 != 0 });
+
+        assert_eq!(2, drops.get());
+        assert_eq!(4, list.len());
+
+        list.retain_mut(|_| true);
+
+        let extracted: Vec<_> = list.extract_if(|_| true).take(3).collect();
+
+        assert_eq!(3, extracted.len());
+        assert_eq!(1, list.len());
+
+        mem::drop(extracted);
+        mem::drop(list);
+
+        assert_eq!(6, drops.get());
+    }
+
+    #[test]
+    fn debug_clone() {
+        let list = LinkedList::from(["a".to_string(), "b".to_string()]);
+        let clone = list.clone();
+
+        assert_eq!(r#"["a", "b"]"#, format!("{:?}", clone));
+        assert_eq!("[]", format!("{:?}", LinkedList::<u32>::new()));
+        assert_eq!(list, clone);
+    }
+
+    #[test]
+    fn eq_ord_hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash(list: &LinkedList<u32>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            list.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let (a, b, c) = (LinkedList::from([1, 2]), LinkedList::from([1, 2]), LinkedList::from([1, 2, 0]));
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(a < c);
+        assert_eq!(Ordering::Greater, LinkedList::from([2]).cmp(&c));
+        assert_eq!(Some(Ordering::Less), LinkedList::from([0.5]).partial_cmp(&LinkedList::from([1.0])));
+        assert_eq!(hash(&a), hash(&b));
+        assert_ne!(hash(&a), hash(&c));
+    }
+
+    #[test]
+    fn from_iter_extend() {
+        let mut list: LinkedList<u32> = (0..3).collect();
+
+        list.extend(vec![3, 4]);
+        list.extend(&[5, 6]);
+
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6], list.iter().copied().collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_conversions() {
+        use std::collections::{LinkedList as StdLinkedList, VecDeque};
+
+        let list = LinkedList::from(StdLinkedList::from([1, 2, 3]));
+
+        assert_eq!(LinkedList::from([1, 2, 3]), list);
+        assert_eq!(StdLinkedList::from([1, 2, 3]), StdLinkedList::from(list));
+
+        let list = LinkedList::from(VecDeque::from([1, 2, 3]));
+
+        assert_eq!(VecDeque::from([1, 2, 3]), VecDeque::from(list));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use crate::{GhostDropSea, GhostSea};
+
+        let list = LinkedList::from(["1".to_string(), "2".to_string()]);
+
+        let json = serde_json::to_string(&list).unwrap();
+
+        assert_eq!(r#"["1","2"]"#, json);
+        assert_eq!(list, serde_json::from_str::<LinkedList<String>>(&json).unwrap());
+
+        let bytes = bincode::serialize(&list).unwrap();
+
+        assert_eq!(list, bincode::deserialize::<LinkedList<String>>(&bytes).unwrap());
+
+        assert!(serde_json::from_str::<LinkedList<u32>>(r#"[1, "2"]"#).is_err());
+
+        let sea: GhostDropSea<Ghost<String>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, serde_json::to_string(&sea).unwrap());
+        assert_eq!(bytes, bincode::serialize(&sea).unwrap());
+
+        let sea: GhostSea<Ghost<String>> = bincode::deserialize(&bytes).unwrap();
+
+        assert_eq!(json, serde_json::to_string(&sea).unwrap());
+
+        sea.apply_once(|mut list, mut token| list.clear(&mut token));
+    }
+}
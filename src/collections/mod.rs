@@ -0,0 +1,19 @@
+//! Collections built upon `GhostCell`, and made self-sufficient by `GhostSea`.
+//!
+//! Each collection comes in two flavors:
+//!
+//! -   A branded flavor, such as `GhostLinkedList<'brand, T>`, which requires the `GhostToken` to be supplied
+//!     externally for each operation, and may therefore share its brand with other data-structures.
+//! -   A stand-alone flavor, such as `LinkedList<T>`, which wraps the branded flavor in a `GhostSea`.
+
+mod ghost_linked_list;
+mod linked_list;
+
+pub use self::ghost_linked_list::{
+    GhostLinkedList, GhostLinkedListCursor, GhostLinkedListCursorMut, GhostLinkedListExtractIf, GhostLinkedListIterator,
+    GhostLinkedListIteratorMut, NodeHandle,
+};
+pub use self::linked_list::{
+    LinkedList, LinkedListCursor, LinkedListCursorMut, LinkedListExtractIf, LinkedListIntoIterator,
+    LinkedListIterator, LinkedListIteratorMut,
+};
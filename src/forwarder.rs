@@ -0,0 +1,415 @@
+//  Helpers to pass callbacks to `GhostSea::apply_ref` and `GhostSea::apply_mut`.
+//
+//  Closures may be passed as is. Closures bound to a variable first, however, lose the higher-ranked signature which
+//  the call would have inferred, hence `ghost_fn!`. Named callbacks, implementing `GhostApplyRef` or `GhostApplyMut`,
+//  are adapted into closures by `ghost_ref` and `ghost_mut`.
+//
+//  The `Ret*` forwarders are ready-made implementations for the common shapes of output, each wrapping a closure
+//  generic over the lifetime of the borrow, which may therefore be named, stored, and used with any borrow.
+
+use core::marker::PhantomData;
+
+use ghost_cell::GhostToken;
+
+use crate::{GhostApplyMut, GhostApplyRef, GhostProject};
+
+/// Adapts a closure for use with `GhostSea::apply_ref` or `GhostSea::apply_mut`.
+///
+/// The invocation is `ghost_fn!(ref|mut Sea, closure)` or `ghost_fn!(ref|mut Sea, Shape, closure)`, where:
+///
+/// -   `ref` or `mut` selects `apply_ref` or `apply_mut`.
+/// -   `Sea` is the un-branded type stored within the `GhostSea`.
+/// -   `Shape` is the shape of the output of the closure, one of:
+///     -   `ref`: `_`, `&_`, `Option<&_>`, `Result<&_, _>` or `(&_, &_)`.
+///     -   `mut`: `_`, `&mut _`, `Option<&mut _>`, `Result<&mut _, _>` or `(&mut _, &mut _)`.
+///
+/// Slices and `str` are covered by `&_`, and `&mut _`.
+///
+/// Closures passed directly to `apply_ref` or `apply_mut` need no help; the macro is meant for closures bound to a
+/// variable first, whose signature would otherwise not be inferred as higher-ranked. With a `Shape`, the closure is
+/// wrapped in the matching `Ret*` forwarder, and is checked against any borrow of the `GhostSea`.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{ghost_fn, GhostCell, GhostProject, GhostSea};
+///
+/// struct Pair<'brand>(GhostCell<'brand, u32>, GhostCell<'brand, u32>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Pair<'static> {
+///     type Branded = Pair<'id>;
+/// }
+///
+/// let mut sea = GhostSea::new(Pair(GhostCell::new(1), GhostCell::new(2)));
+///
+/// let swap = ghost_fn!(mut Pair<'static>, |pair, token| {
+///     let first = *pair.0.borrow(token);
+///     *pair.0.borrow_mut(token) = *pair.1.borrow(token);
+///     *pair.1.borrow_mut(token) = first;
+/// });
+///
+/// sea.apply_mut(swap);
+///
+/// let sum = ghost_fn!(ref Pair<'static>, |pair, token| *pair.0.borrow(token) * 10 + *pair.1.borrow(token));
+///
+/// assert_eq!(21, sea.apply_ref(sum));
+///
+/// *sea.apply_mut(ghost_fn!(mut Pair<'static>, &mut _, |pair, token| pair.1.borrow_mut(token))) += 1;
+///
+/// let (first, second) = sea.apply_ref(ghost_fn!(ref Pair<'static>, (&_, &_), |pair, token| {
+///     (pair.0.borrow(token), pair.1.borrow(token))
+/// }));
+///
+/// assert_eq!((&2, &2), (first, second));
+/// ```
+#[macro_export]
+macro_rules! ghost_fn {
+    (ref $sea:ty, $fun:expr) => { $crate::__private::ref_fn::<$sea, _, _>($fun) };
+    (mut $sea:ty, $fun:expr) => { $crate::__private::mut_fn::<$sea, _, _>($fun) };
+    (ref $sea:ty, _, $fun:expr) => { $crate::ghost_ref($crate::RetValueRef::<$sea, _, _>::new($fun)) };
+    (ref $sea:ty, &_, $fun:expr) => { $crate::ghost_ref($crate::RetReferenceRef::<$sea, _, _>::new($fun)) };
+    (ref $sea:ty, Option<&_>, $fun:expr) => { $crate::ghost_ref($crate::RetOptionalRef::<$sea, _, _>::new($fun)) };
+    (ref $sea:ty, Result<&_, _>, $fun:expr) => {
+        $crate::ghost_ref($crate::RetResultRef::<$sea, _, _, _>::new($fun))
+    };
+    (ref $sea:ty, (&_, &_), $fun:expr) => { $crate::ghost_ref($crate::RetPairRef::<$sea, _, _, _>::new($fun)) };
+    (mut $sea:ty, _, $fun:expr) => { $crate::ghost_mut($crate::RetValueMut::<$sea, _, _>::new($fun)) };
+    (mut $sea:ty, &mut _, $fun:expr) => { $crate::ghost_mut($crate::RetReferenceMut::<$sea, _, _>::new($fun)) };
+    (mut $sea:ty, Option<&mut _>, $fun:expr) => {
+        $crate::ghost_mut($crate::RetOptionalMut::<$sea, _, _>::new($fun))
+    };
+    (mut $sea:ty, Result<&mut _, _>, $fun:expr) => {
+        $crate::ghost_mut($crate::RetResultMut::<$sea, _, _, _>::new($fun))
+    };
+    (mut $sea:ty, (&mut _, &mut _), $fun:expr) => {
+        $crate::ghost_mut($crate::RetPairMut::<$sea, _, _, _>::new($fun))
+    };
+}
+
+/// Adapts an implementation of `GhostApplyRef` for use with `GhostSea::apply_ref`.
+///
+/// #   Migration
+///
+/// Implementations of the former `GhostApplyRef<'id, T>` were passed as is, `sea.apply_ref(x)`; once rewritten
+/// against the current trait, as described in the documentation of `GhostApplyRef`, they are passed as
+/// `sea.apply_ref(ghost_ref(x))`.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{ghost_ref, GhostApplyRef, GhostCell, GhostProject, GhostSea, GhostToken};
+///
+/// struct Name<'brand>(GhostCell<'brand, String>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Name<'static> {
+///     type Branded = Name<'id>;
+/// }
+///
+/// struct AsStr;
+///
+/// impl<'a> GhostApplyRef<'a, Name<'static>> for AsStr {
+///     type Output = &'a str;
+///
+///     fn call<'id>(self, name: &'a Name<'id>, token: &'a GhostToken<'id>) -> &'a str { name.0.borrow(token) }
+/// }
+///
+/// let sea = GhostSea::new(Name(GhostCell::new("Alice".to_string())));
+///
+/// assert_eq!("Alice", sea.apply_ref(ghost_ref(AsStr)));
+/// ```
+#[inline(always)]
+pub fn ghost_ref<'a, T, G>(
+    fun: G,
+) -> impl for<'id> FnOnce(&'a <T as GhostProject<'id>>::Branded, &'a GhostToken<'id>) -> G::Output
+where
+    T: for<'x> GhostProject<'x>,
+    G: GhostApplyRef<'a, T>,
+{
+    move |ghost, token| fun.call(ghost, token)
+}
+
+/// Adapts an implementation of `GhostApplyMut` for use with `GhostSea::apply_mut`.
+///
+/// #   Migration
+///
+/// Implementations of the former `GhostApplyMut<'id, T>` were passed as is, `sea.apply_mut(x)`; once rewritten
+/// against the current trait, as described in the documentation of `GhostApplyMut`, they are passed as
+/// `sea.apply_mut(ghost_mut(x))`.
+///
+/// See `ghost_ref` for an example.
+#[inline(always)]
+pub fn ghost_mut<'a, T, G>(
+    fun: G,
+) -> impl for<'id> FnOnce(&'a mut <T as GhostProject<'id>>::Branded, &'a mut GhostToken<'id>) -> G::Output
+where
+    T: for<'x> GhostProject<'x>,
+    G: GhostApplyMut<'a, T>,
+{
+    move |ghost, token| fun.call(ghost, token)
+}
+
+
+//  Defines a forwarder, wrapping a closure generic over the borrow `$any`, whose output is `$any_output`, and
+//  implementing the apply trait for the borrow `$borrow`, whose output is `$output`.
+//
+//  The sized parameters of the output are listed first, and the unsized ones second.
+macro_rules! forwarder {
+    (
+        ref,
+        $(#[$meta:meta])*
+        $forwarder:ident[$($sized:ident),*][$($unsized:ident),*],
+        $borrow:lifetime => $output:ty,
+        $any:lifetime => $any_output:ty
+    ) => {
+        $(#[$meta])*
+        pub struct $forwarder<S, $($sized,)* $($unsized: ?Sized,)* F>(
+            F,
+            PhantomData<fn(&S) -> ($(*const $sized,)* $(*const $unsized,)*)>,
+        );
+
+        impl<S, $($sized,)* $($unsized: ?Sized,)* F> $forwarder<S, $($sized,)* $($unsized,)* F>
+        where
+            S: for<'x> GhostProject<'x>,
+            F: for<$any, 'id> FnOnce(&$any <S as GhostProject<'id>>::Branded, &$any GhostToken<'id>) -> $any_output,
+        {
+            /// Creates an instance.
+            #[inline(always)]
+            pub fn new(fun: F) -> Self { Self(fun, PhantomData) }
+        }
+
+        impl<$borrow, S, $($sized,)* $($unsized: ?Sized,)* F> GhostApplyRef<$borrow, S>
+            for $forwarder<S, $($sized,)* $($unsized,)* F>
+        where
+            S: for<'x> GhostProject<'x>,
+            $($sized: $borrow,)*
+            $($unsized: $borrow,)*
+            F: for<$any, 'id> FnOnce(&$any <S as GhostProject<'id>>::Branded, &$any GhostToken<'id>) -> $any_output,
+        {
+            type Output = $output;
+
+            #[inline(always)]
+            fn call<'id>(
+                self,
+                ghost: &$borrow <S as GhostProject<'id>>::Branded,
+                token: &$borrow GhostToken<'id>,
+            ) -> Self::Output {
+                (self.0)(ghost, token)
+            }
+        }
+    };
+    (
+        mut,
+        $(#[$meta:meta])*
+        $forwarder:ident[$($sized:ident),*][$($unsized:ident),*],
+        $borrow:lifetime => $output:ty,
+        $any:lifetime => $any_output:ty
+    ) => {
+        $(#[$meta])*
+        pub struct $forwarder<S, $($sized,)* $($unsized: ?Sized,)* F>(
+            F,
+            PhantomData<fn(&S) -> ($(*const $sized,)* $(*const $unsized,)*)>,
+        );
+
+        impl<S, $($sized,)* $($unsized: ?Sized,)* F> $forwarder<S, $($sized,)* $($unsized,)* F>
+        where
+            S: for<'x> GhostProject<'x>,
+            F: for<$any, 'id> FnOnce(
+                &$any mut <S as GhostProject<'id>>::Branded,
+                &$any mut GhostToken<'id>,
+            ) -> $any_output,
+        {
+            /// Creates an instance.
+            #[inline(always)]
+            pub fn new(fun: F) -> Self { Self(fun, PhantomData) }
+        }
+
+        impl<$borrow, S, $($sized,)* $($unsized: ?Sized,)* F> GhostApplyMut<$borrow, S>
+            for $forwarder<S, $($sized,)* $($unsized,)* F>
+        where
+            S: for<'x> GhostProject<'x>,
+            $($sized: $borrow,)*
+            $($unsized: $borrow,)*
+            F: for<$any, 'id> FnOnce(
+                &$any mut <S as GhostProject<'id>>::Branded,
+                &$any mut GhostToken<'id>,
+            ) -> $any_output,
+        {
+            type Output = $output;
+
+            #[inline(always)]
+            fn call<'id>(
+                self,
+                ghost: &$borrow mut <S as GhostProject<'id>>::Branded,
+                token: &$borrow mut GhostToken<'id>,
+            ) -> Self::Output {
+                (self.0)(ghost, token)
+            }
+        }
+    };
+}
+
+forwarder!(ref,
+    /// Forwarder for `GhostSea::apply_ref`, returning a value.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{ghost_ref, GhostCell, GhostProject, GhostSea, RetValueRef};
+    ///
+    /// struct Name<'brand>(GhostCell<'brand, String>);
+    ///
+    /// unsafe impl<'id> GhostProject<'id> for Name<'static> {
+    ///     type Branded = Name<'id>;
+    /// }
+    ///
+    /// let sea = GhostSea::new(Name(GhostCell::new("Alice".to_string())));
+    ///
+    /// let len = RetValueRef::<Name<'static>, _, _>::new(|name, token| name.0.borrow(token).len());
+    ///
+    /// assert_eq!(5, sea.apply_ref(ghost_ref(len)));
+    /// ```
+    RetValueRef[R][], 'a => R, 'x => R);
+
+forwarder!(mut,
+    /// Forwarder for `GhostSea::apply_mut`, returning a value.
+    ///
+    /// See `RetValueRef` for an example.
+    RetValueMut[R][], 'a => R, 'x => R);
+
+forwarder!(ref,
+    /// Forwarder for `GhostSea::apply_ref`, returning a reference, a slice, or a `str`.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{ghost_ref, GhostCell, GhostProject, GhostSea, RetReferenceRef};
+    ///
+    /// struct Scores<'brand>(GhostCell<'brand, Vec<u32>>);
+    ///
+    /// unsafe impl<'id> GhostProject<'id> for Scores<'static> {
+    ///     type Branded = Scores<'id>;
+    /// }
+    ///
+    /// let sea = GhostSea::new(Scores(GhostCell::new(vec![3, 1, 2])));
+    ///
+    /// let scores = RetReferenceRef::<Scores<'static>, _, _>::new(|scores, token| scores.0.borrow(token));
+    ///
+    /// assert_eq!(&vec![3, 1, 2], sea.apply_ref(ghost_ref(scores)));
+    ///
+    /// let tail = RetReferenceRef::<Scores<'static>, _, _>::new(|scores, token| &scores.0.borrow(token)[1..]);
+    ///
+    /// assert_eq!(&[1, 2], sea.apply_ref(ghost_ref(tail)));
+    /// ```
+    RetReferenceRef[][R], 'a => &'a R, 'x => &'x R);
+
+forwarder!(mut,
+    /// Forwarder for `GhostSea::apply_mut`, returning a mutable reference, a slice, or a `str`.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{ghost_mut, GhostCell, GhostProject, GhostSea, RetReferenceMut};
+    ///
+    /// struct Name<'brand>(GhostCell<'brand, String>);
+    ///
+    /// unsafe impl<'id> GhostProject<'id> for Name<'static> {
+    ///     type Branded = Name<'id>;
+    /// }
+    ///
+    /// let mut sea = GhostSea::new(Name(GhostCell::new("Alice".to_string())));
+    ///
+    /// let name = RetReferenceMut::<Name<'static>, _, _>::new(|name, token| name.0.borrow_mut(token));
+    ///
+    /// sea.apply_mut(ghost_mut(name)).push('!');
+    ///
+    /// assert_eq!("Alice!", sea.apply_ref(|name, token| name.0.borrow(token).as_str()));
+    /// ```
+    RetReferenceMut[][R], 'a => &'a mut R, 'x => &'x mut R);
+
+forwarder!(ref,
+    /// Forwarder for `GhostSea::apply_ref`, returning an optional reference.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{ghost_ref, GhostCell, GhostProject, GhostSea, RetOptionalRef};
+    ///
+    /// struct Scores<'brand>(GhostCell<'brand, Vec<u32>>);
+    ///
+    /// unsafe impl<'id> GhostProject<'id> for Scores<'static> {
+    ///     type Branded = Scores<'id>;
+    /// }
+    ///
+    /// let sea = GhostSea::new(Scores(GhostCell::new(vec![3, 1, 2])));
+    ///
+    /// let best = RetOptionalRef::<Scores<'static>, _, _>::new(|scores, token| scores.0.borrow(token).iter().max());
+    ///
+    /// assert_eq!(Some(&3), sea.apply_ref(ghost_ref(best)));
+    /// ```
+    RetOptionalRef[][R], 'a => Option<&'a R>, 'x => Option<&'x R>);
+
+forwarder!(mut,
+    /// Forwarder for `GhostSea::apply_mut`, returning an optional mutable reference.
+    ///
+    /// See `RetOptionalRef` for an example.
+    RetOptionalMut[][R], 'a => Option<&'a mut R>, 'x => Option<&'x mut R>);
+
+forwarder!(ref,
+    /// Forwarder for `GhostSea::apply_ref`, returning either a reference or an error.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{ghost_ref, GhostCell, GhostProject, GhostSea, RetResultRef};
+    ///
+    /// struct Scores<'brand>(GhostCell<'brand, Vec<u32>>);
+    ///
+    /// unsafe impl<'id> GhostProject<'id> for Scores<'static> {
+    ///     type Branded = Scores<'id>;
+    /// }
+    ///
+    /// let sea = GhostSea::new(Scores(GhostCell::new(vec![3, 1, 2])));
+    ///
+    /// let first = RetResultRef::<Scores<'static>, _, _, _>::new(|scores, token| {
+    ///     scores.0.borrow(token).first().ok_or("empty")
+    /// });
+    ///
+    /// assert_eq!(Ok(&3), sea.apply_ref(ghost_ref(first)));
+    /// ```
+    RetResultRef[E][R], 'a => Result<&'a R, E>, 'x => Result<&'x R, E>);
+
+forwarder!(mut,
+    /// Forwarder for `GhostSea::apply_mut`, returning either a mutable reference or an error.
+    ///
+    /// See `RetResultRef` for an example.
+    RetResultMut[E][R], 'a => Result<&'a mut R, E>, 'x => Result<&'x mut R, E>);
+
+forwarder!(ref,
+    /// Forwarder for `GhostSea::apply_ref`, returning a pair of references.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{ghost_ref, GhostCell, GhostProject, GhostSea, RetPairRef};
+    ///
+    /// struct Pair<'brand>(GhostCell<'brand, String>, GhostCell<'brand, u32>);
+    ///
+    /// unsafe impl<'id> GhostProject<'id> for Pair<'static> {
+    ///     type Branded = Pair<'id>;
+    /// }
+    ///
+    /// let sea = GhostSea::new(Pair(GhostCell::new("Alice".to_string()), GhostCell::new(42)));
+    ///
+    /// let both = RetPairRef::<Pair<'static>, _, _, _>::new(|pair, token| {
+    ///     (pair.0.borrow(token).as_str(), pair.1.borrow(token))
+    /// });
+    ///
+    /// assert_eq!(("Alice", &42), sea.apply_ref(ghost_ref(both)));
+    /// ```
+    RetPairRef[][R, Q], 'a => (&'a R, &'a Q), 'x => (&'x R, &'x Q));
+
+forwarder!(mut,
+    /// Forwarder for `GhostSea::apply_mut`, returning a pair of mutable references.
+    ///
+    /// See `RetPairRef` for an example.
+    RetPairMut[][R, Q], 'a => (&'a mut R, &'a mut Q), 'x => (&'x mut R, &'x mut Q));
@@ -0,0 +1,453 @@
+//  Cloning of branded data.
+//
+//  Cloning branded data requires access to its `GhostToken`, and additionally to keep track of shared allocations: two
+//  halves of a node should be cloned as two halves of a single new node, not as two distinct nodes. This is the role of
+//  the `GhostCloneContext`, which maps the address of each original allocation to its clone.
+
+use core::{
+    mem::{self, ManuallyDrop},
+    ptr::NonNull,
+};
+
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, VecDeque},
+    rc::Rc,
+    string::String,
+    sync::Arc,
+    vec::Vec,
+};
+
+use ghost_cell::{GhostCell, GhostToken};
+use static_rc::StaticRc;
+
+/// Token-aware equivalent of `core::clone::Clone`.
+///
+/// #   Safety
+///
+/// The clone is paired with a fresh token by `GhostSea::clone`, hence it shall not share any branded data, such as a
+/// `GhostCell`, with the original.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostClone, GhostCloneContext, GhostProject, GhostSea, GhostToken};
+///
+/// struct Name<'brand>(GhostCell<'brand, String>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Name<'static> {
+///     type Branded = Name<'id>;
+/// }
+///
+/// unsafe impl<'brand> GhostClone<'brand> for Name<'brand> {
+///     fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self {
+///         Name(self.0.ghost_clone(context, token))
+///     }
+/// }
+///
+/// let mut alice = GhostSea::new(Name(GhostCell::new("Alice".to_string())));
+/// let bob = alice.clone();
+///
+/// alice.apply_mut(|name, token| name.0.borrow_mut(token).push_str(" & Carol"));
+///
+/// assert_eq!("Alice & Carol", alice.apply_ref(|name, token| name.0.borrow(token).as_str()));
+/// assert_eq!("Alice", bob.apply_ref(|name, token| name.0.borrow(token).as_str()));
+/// ```
+///
+/// Containers, such as `Option`, `Result`, `Vec` or `VecDeque`, clone each of their elements:
+///
+/// ```
+/// use std::collections::VecDeque;
+///
+/// use ghost_sea::{GhostCell, GhostClone, GhostCloneContext, GhostToken};
+///
+/// GhostToken::new(|mut token| {
+///     let queue: VecDeque<_> = (1..=3).map(GhostCell::new).collect();
+///     let result: Result<GhostCell<'_, u32>, String> = Ok(GhostCell::new(4));
+///
+///     let mut context = GhostCloneContext::new();
+///     let queue_clone = queue.ghost_clone(&mut context, &token);
+///     let result_clone = result.ghost_clone(&mut context, &token);
+///
+///     *queue_clone[0].borrow_mut(&mut token) = 10;
+///     *result_clone.as_ref().unwrap().borrow_mut(&mut token) = 40;
+///
+///     assert_eq!(vec![1, 2, 3], queue.iter().map(|cell| *cell.borrow(&token)).collect::<Vec<_>>());
+///     assert_eq!(vec![10, 2, 3], queue_clone.iter().map(|cell| *cell.borrow(&token)).collect::<Vec<_>>());
+///     assert_eq!(4, *result.unwrap().borrow(&token));
+///     assert_eq!(40, *result_clone.unwrap().borrow(&token));
+///
+///     let error: Result<GhostCell<'_, u32>, String> = Err("missing".to_string());
+///
+///     assert_eq!(Err("missing".to_string()), error.ghost_clone(&mut context, &token).map(|_| ()));
+/// });
+/// ```
+pub unsafe trait GhostClone<'brand>: Sized {
+    /// Returns a clone of `self`, registering shared allocations in `context`.
+    fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self;
+}
+
+/// Context of a clone, mapping original shared allocations to their clones.
+///
+/// A single context should be used for the clone of a whole structure, so that sharing is preserved throughout.
+///
+/// Cyclic structures built from shared pointers cannot be cloned this way, as an allocation would be encountered while
+/// it is being cloned; collections such as `GhostLinkedList` instead relink their own nodes.
+///
+/// #   Examples
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use ghost_sea::{GhostCell, GhostClone, GhostCloneContext, GhostToken};
+///
+/// GhostToken::new(|token| {
+///     let shared = Rc::new(GhostCell::new(1));
+///     let pair = (shared.clone(), shared);
+///
+///     let clone = pair.ghost_clone(&mut GhostCloneContext::new(), &token);
+///
+///     assert!(Rc::ptr_eq(&clone.0, &clone.1));
+///     assert!(!Rc::ptr_eq(&clone.0, &pair.0));
+/// });
+/// ```
+///
+/// Similarly, two halves of a `StaticRc` are cloned as two halves of a single new allocation, which are joined back and
+/// released like the originals:
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostClone, GhostCloneContext, GhostToken};
+/// use static_rc::StaticRc;
+///
+/// GhostToken::new(|token| {
+///     type Half<'brand> = StaticRc<GhostCell<'brand, i32>, 1, 2>;
+///     type Full<'brand> = StaticRc<GhostCell<'brand, i32>, 2, 2>;
+///
+///     let pair: (Half<'_>, Half<'_>) = StaticRc::split::<1, 1>(Full::new(GhostCell::new(1)));
+///
+///     let (left_clone, right_clone) = pair.ghost_clone(&mut GhostCloneContext::new(), &token);
+///
+///     assert!(StaticRc::ptr_eq(&left_clone, &right_clone));
+///
+///     let full: Full<'_> = StaticRc::join(left_clone, right_clone);
+///     assert_eq!(1, *full.borrow(&token));
+///
+///     let _: Full<'_> = StaticRc::join(pair.0, pair.1);
+/// });
+/// ```
+///
+/// The shares of the new allocation are handed out as the original shares are encountered, whatever their ratio, for
+/// example after `StaticRc::adjust`:
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostClone, GhostCloneContext, GhostToken};
+/// use static_rc::StaticRc;
+///
+/// GhostToken::new(|token| {
+///     type Half<'brand> = StaticRc<GhostCell<'brand, i32>, 1, 2>;
+///     type Quarters<'brand> = StaticRc<GhostCell<'brand, i32>, 2, 4>;
+///     type Full<'brand> = StaticRc<GhostCell<'brand, i32>, 2, 2>;
+///
+///     let (half, other): (Half<'_>, Half<'_>) = StaticRc::split::<1, 1>(Full::new(GhostCell::new(1)));
+///     let pair: (Half<'_>, Quarters<'_>) = (half, StaticRc::adjust::<2, 4>(other));
+///
+///     let (half_clone, quarters_clone) = pair.ghost_clone(&mut GhostCloneContext::new(), &token);
+///
+///     let full: Full<'_> = StaticRc::join(half_clone, StaticRc::adjust::<1, 2>(quarters_clone));
+///     assert_eq!(1, *full.borrow(&token));
+///
+///     let _: Full<'_> = StaticRc::join(pair.0, StaticRc::adjust::<1, 2>(pair.1));
+/// });
+/// ```
+///
+/// Should only part of the original shares be reachable from the value being cloned, the remaining shares of the new
+/// allocation are never handed out: the clones can never be joined back into a whole, and the allocation, along with
+/// its value, is leaked.
+#[derive(Default)]
+pub struct GhostCloneContext {
+    clones: BTreeMap<*const (), Shared>,
+}
+
+impl GhostCloneContext {
+    /// Creates a new, empty, context.
+    pub fn new() -> Self { Self::default() }
+
+    //  Returns the clone of `original`, if already cloned.
+    //
+    //  #   Panics
+    //
+    //  If `original` is being cloned, that is if the structure is cyclic.
+    fn get<T>(&mut self, original: *const T) -> Option<&mut Shared> {
+        let shared = self.clones.get_mut(&(original as *const ()))?;
+
+        assert!(shared.pointer.is_some(), "Cyclic structures cannot be cloned");
+
+        Some(shared)
+    }
+
+    //  Marks `original` as being cloned.
+    fn start<T>(&mut self, original: *const T) {
+        let shared = Shared { pointer: None, shares: (0, 1), release: Shared::leak };
+
+        self.clones.insert(original as *const (), shared);
+    }
+
+    //  Registers the clone of `original`, from which `shares`, as a ratio, remain to be handed out.
+    fn finish<T>(
+        &mut self,
+        original: *const T,
+        pointer: NonNull<T>,
+        shares: (usize, usize),
+        release: unsafe fn(NonNull<()>),
+    ) {
+        let shared = Shared { pointer: Some(pointer.cast()), shares, release };
+
+        self.clones.insert(original as *const (), shared);
+    }
+}
+
+impl Drop for GhostCloneContext {
+    fn drop(&mut self) {
+        for (_, shared) in mem::take(&mut self.clones) {
+            if let Some(pointer) = shared.pointer {
+                //  Safety:
+                //  -   `release` was registered alongside `pointer`.
+                unsafe { (shared.release)(pointer) };
+            }
+        }
+    }
+}
+
+//
+//  Implementations
+//
+
+macro_rules! brand_free {
+    ($($t:ty),*) => {
+        $(
+            //  Safety:
+            //  -   There is no branded data, hence nothing to share.
+            unsafe impl<'brand> GhostClone<'brand> for $t {
+                #[inline(always)]
+                fn ghost_clone(&self, _: &mut GhostCloneContext, _: &GhostToken<'brand>) -> Self { self.clone() }
+            }
+        )*
+    };
+}
+
+brand_free!((), bool, char, f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, String);
+
+//  Safety:
+//  -   A new cell is created.
+unsafe impl<'brand, T: GhostClone<'brand>> GhostClone<'brand> for GhostCell<'brand, T> {
+    fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self {
+        GhostCell::new(self.borrow(token).ghost_clone(context, token))
+    }
+}
+
+//  Safety:
+//  -   Each element is cloned, as per its own implementation.
+unsafe impl<'brand, T: GhostClone<'brand>> GhostClone<'brand> for Option<T> {
+    fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self {
+        self.as_ref().map(|value| value.ghost_clone(context, token))
+    }
+}
+
+//  Safety:
+//  -   Each element is cloned, as per its own implementation.
+unsafe impl<'brand, T: GhostClone<'brand>, const N: usize> GhostClone<'brand> for [T; N] {
+    fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self {
+        core::array::from_fn(|index| self[index].ghost_clone(context, token))
+    }
+}
+
+//  Safety:
+//  -   Each element is cloned, as per its own implementation.
+unsafe impl<'brand, T: GhostClone<'brand>> GhostClone<'brand> for Box<T> {
+    fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self {
+        Box::new((**self).ghost_clone(context, token))
+    }
+}
+
+//  Safety:
+//  -   Each element is cloned, as per its own implementation.
+unsafe impl<'brand, T: GhostClone<'brand>> GhostClone<'brand> for Vec<T> {
+    fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self {
+        self.iter().map(|value| value.ghost_clone(context, token)).collect()
+    }
+}
+
+//  Safety:
+//  -   Each element is cloned, as per its own implementation.
+unsafe impl<'brand, T: GhostClone<'brand>> GhostClone<'brand> for VecDeque<T> {
+    fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self {
+        self.iter().map(|value| value.ghost_clone(context, token)).collect()
+    }
+}
+
+//  Safety:
+//  -   Each element is cloned, as per its own implementation.
+unsafe impl<'brand, T: GhostClone<'brand>, E: GhostClone<'brand>> GhostClone<'brand> for Result<T, E> {
+    fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self {
+        match self {
+            Ok(value) => Ok(value.ghost_clone(context, token)),
+            Err(error) => Err(error.ghost_clone(context, token)),
+        }
+    }
+}
+
+macro_rules! shared {
+    ($($p:ident),*) => {
+        $(
+            //  Safety:
+            //  -   A new allocation is created for each original allocation.
+            unsafe impl<'brand, T: GhostClone<'brand>> GhostClone<'brand> for $p<T> {
+                fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self {
+                    let original = $p::as_ptr(self);
+
+                    if let Some(shared) = context.get(original) {
+                        let pointer = shared.pointer().cast::<T>().as_ptr();
+
+                        //  Safety:
+                        //  -   `pointer` was obtained from `into_raw` on a clone of the same type, registered at the
+                        //      address of the original, and the context holds onto a strong count.
+                        return unsafe {
+                            $p::increment_strong_count(pointer);
+                            $p::from_raw(pointer)
+                        };
+                    }
+
+                    context.start(original);
+
+                    let clone = $p::new((**self).ghost_clone(context, token));
+
+                    //  Safety:
+                    //  -   `into_raw` never returns a null pointer.
+                    let pointer = unsafe { NonNull::new_unchecked($p::into_raw($p::clone(&clone)) as *mut T) };
+
+                    //  Safety:
+                    //  -   `pointer` was obtained from `into_raw`, and holds a strong count.
+                    unsafe fn release<T>(pointer: NonNull<()>) { drop($p::from_raw(pointer.cast::<T>().as_ptr())) }
+
+                    context.finish(original, pointer, (0, 1), release::<T>);
+
+                    clone
+                }
+            }
+        )*
+    };
+}
+
+shared!(Rc, Arc);
+
+//  Safety:
+//  -   A new allocation is created for each original allocation.
+unsafe impl<'brand, T, const NUM: usize, const DEN: usize> GhostClone<'brand> for StaticRc<T, NUM, DEN>
+where
+    T: GhostClone<'brand>,
+{
+    fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self {
+        let original = StaticRc::as_ptr(self).as_ptr() as *const T;
+
+        //  Zero-sized values all share the same dangling address, and cannot be told apart; their shares are not
+        //  tracked, and the remainder of each is simply leaked.
+        let tracked = mem::size_of::<T>() != 0;
+
+        if let Some(shared) = if tracked { context.get(original) } else { None } {
+            shared.hand_out(NUM, DEN);
+
+            //  Safety:
+            //  -   `pointer` was leaked from a clone of the same type, registered at the address of the original,
+            //      and the remaining shares are accounted for.
+            return unsafe { StaticRc::from_raw(shared.pointer().cast()) };
+        }
+
+        if tracked {
+            context.start(original);
+        }
+
+        //  `StaticRc::into_raw` drops its argument in 0.2, hence `ManuallyDrop`.
+        let clone = ManuallyDrop::new(StaticRc::<T, DEN, DEN>::new((**self).ghost_clone(context, token)));
+        let pointer = StaticRc::as_ptr(&clone);
+
+        if tracked {
+            context.finish(original, pointer, (DEN - NUM, DEN), Shared::leak);
+        }
+
+        //  Safety:
+        //  -   `pointer` was leaked from a full `StaticRc`, and the remaining shares are accounted for in `context`.
+        unsafe { StaticRc::from_raw(pointer) }
+    }
+}
+
+macro_rules! tuple {
+    ($($name:ident $index:tt),+) => {
+        //  Safety:
+        //  -   Each element is cloned, as per its own implementation.
+        unsafe impl<'brand, $($name: GhostClone<'brand>),+> GhostClone<'brand> for ($($name,)+) {
+            fn ghost_clone(&self, context: &mut GhostCloneContext, token: &GhostToken<'brand>) -> Self {
+                ($(self.$index.ghost_clone(context, token),)+)
+            }
+        }
+    };
+}
+
+tuple!(A 0);
+tuple!(A 0, B 1);
+tuple!(A 0, B 1, C 2);
+tuple!(A 0, B 1, C 2, D 3);
+tuple!(A 0, B 1, C 2, D 3, E 4);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+//
+//  Implementation
+//
+
+//  The clone of a shared allocation.
+struct Shared {
+    //  The clone, or `None` while it is being cloned.
+    pointer: Option<NonNull<()>>,
+    //  The ratio of shares left to hand out, as numerator and denominator, for `StaticRc`.
+    shares: (usize, usize),
+    //  Releases the hold of the context on `pointer`.
+    release: unsafe fn(NonNull<()>),
+}
+
+impl Shared {
+    fn pointer(&self) -> NonNull<()> { self.pointer.expect("Clone should be complete") }
+
+    //  Hands out `num / den` of the shares left.
+    //
+    //  The shares of the originals add up to at most a whole, hence never exceed those left.
+    fn hand_out(&mut self, num: usize, den: usize) {
+        let (left, left_den) = self.shares;
+
+        let (left, handed) = (left * den, num * left_den);
+
+        debug_assert!(handed <= left, "Shares of a `StaticRc` should add up to at most a whole");
+
+        let (num, den) = (left - handed, left_den * den);
+        let divisor = gcd(num, den);
+
+        self.shares = (num / divisor, den / divisor);
+    }
+
+    //  Shares of a `StaticRc` which were never handed out cannot be reclaimed, as their ratio is only known at
+    //  run-time; much like partial shares of a `StaticRc`, they are leaked.
+    unsafe fn leak(_: NonNull<()>) {}
+}
+
+//  Returns the greatest common divisor of `a` and `b`, or `b` if `a` is 0.
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while a != 0 {
+        (a, b) = (b % a, a);
+    }
+
+    b
+}
@@ -0,0 +1,496 @@
+//  Comparison and hashing of branded data.
+//
+//  Much like formatting, see `GhostDebug`, comparing or hashing branded data requires access to its `GhostToken`, hence
+//  token-aware equivalents of `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash`.
+
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use ghost_cell::{GhostCell, GhostToken};
+
+use crate::WithToken;
+
+/// Token-aware equivalent of `core::cmp::PartialEq`.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostPartialEq, GhostProject, GhostSea, GhostToken};
+///
+/// struct Name<'brand>(GhostCell<'brand, &'static str>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Name<'static> {
+///     type Branded = Name<'id>;
+/// }
+///
+/// impl<'brand> GhostPartialEq<'brand> for Name<'brand> {
+///     fn ghost_eq(&self, other: &Self, token: &GhostToken<'brand>) -> bool {
+///         self.0.ghost_eq(&other.0, token)
+///     }
+/// }
+///
+/// let alice = GhostSea::new(Name(GhostCell::new("Alice")));
+/// let bob = GhostSea::new(Name(GhostCell::new("Bob")));
+///
+/// assert!(alice == alice);
+/// assert!(alice != bob);
+/// ```
+pub trait GhostPartialEq<'brand, Rhs: ?Sized = Self> {
+    /// Returns whether `self` and `other` are equal.
+    fn ghost_eq(&self, other: &Rhs, token: &GhostToken<'brand>) -> bool;
+
+    /// Returns whether `self` and `other` are different.
+    #[inline(always)]
+    fn ghost_ne(&self, other: &Rhs, token: &GhostToken<'brand>) -> bool { !self.ghost_eq(other, token) }
+}
+
+/// Token-aware equivalent of `core::cmp::Eq`.
+pub trait GhostEq<'brand>: GhostPartialEq<'brand> {}
+
+/// Token-aware equivalent of `core::cmp::PartialOrd`.
+pub trait GhostPartialOrd<'brand, Rhs: ?Sized = Self>: GhostPartialEq<'brand, Rhs> {
+    /// Returns the ordering between `self` and `other`, if any.
+    fn ghost_partial_cmp(&self, other: &Rhs, token: &GhostToken<'brand>) -> Option<Ordering>;
+}
+
+/// Token-aware equivalent of `core::cmp::Ord`.
+///
+/// #   Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+///
+/// use ghost_sea::{GhostCell, GhostEq, GhostOrd, GhostPartialEq, GhostPartialOrd, GhostProject, GhostSea, GhostToken};
+///
+/// struct Name<'brand>(GhostCell<'brand, &'static str>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Name<'static> {
+///     type Branded = Name<'id>;
+/// }
+///
+/// impl<'brand> GhostPartialEq<'brand> for Name<'brand> {
+///     fn ghost_eq(&self, other: &Self, token: &GhostToken<'brand>) -> bool { self.0.ghost_eq(&other.0, token) }
+/// }
+///
+/// impl<'brand> GhostEq<'brand> for Name<'brand> {}
+///
+/// impl<'brand> GhostPartialOrd<'brand> for Name<'brand> {
+///     fn ghost_partial_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Option<Ordering> {
+///         Some(self.ghost_cmp(other, token))
+///     }
+/// }
+///
+/// impl<'brand> GhostOrd<'brand> for Name<'brand> {
+///     fn ghost_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Ordering { self.0.ghost_cmp(&other.0, token) }
+/// }
+///
+/// let (alice, bob) = (GhostSea::new(Name(GhostCell::new("Alice"))), GhostSea::new(Name(GhostCell::new("Bob"))));
+/// let other_alice = GhostSea::new(Name(GhostCell::new("Alice")));
+///
+/// assert_eq!(Ordering::Less, alice.cmp(&bob));
+/// assert_eq!(Some(Ordering::Greater), bob.partial_cmp(&alice));
+/// assert_eq!(Ordering::Equal, alice.cmp(&other_alice));
+/// assert!(alice == other_alice);
+/// assert!(bob != other_alice);
+/// ```
+pub trait GhostOrd<'brand>: GhostEq<'brand> + GhostPartialOrd<'brand> {
+    /// Returns the ordering between `self` and `other`.
+    fn ghost_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Ordering;
+}
+
+/// Token-aware equivalent of `core::hash::Hash`.
+///
+/// As with `Hash`, values which are equal according to `GhostPartialEq` should hash identically.
+///
+/// #   Examples
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use ghost_sea::{GhostCell, GhostToken, WithToken};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new(1), GhostCell::new(2), GhostCell::new(1)];
+///
+///     let unique: HashSet<_> = cells.iter().map(|cell| WithToken::new(cell, &token)).collect();
+///
+///     assert_eq!(2, unique.len());
+/// });
+/// ```
+pub trait GhostHash<'brand> {
+    /// Feeds `self` into `state`.
+    fn ghost_hash<H: Hasher>(&self, state: &mut H, token: &GhostToken<'brand>);
+}
+
+//
+//  WithToken
+//
+
+impl<'a, 'brand, T: ?Sized + GhostPartialEq<'brand>> PartialEq for WithToken<'a, 'brand, T> {
+    fn eq(&self, other: &Self) -> bool { self.value().ghost_eq(other.value(), self.token()) }
+}
+
+impl<'a, 'brand, T: ?Sized + GhostEq<'brand>> Eq for WithToken<'a, 'brand, T> {}
+
+impl<'a, 'brand, T: ?Sized + GhostPartialOrd<'brand>> PartialOrd for WithToken<'a, 'brand, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value().ghost_partial_cmp(other.value(), self.token())
+    }
+}
+
+impl<'a, 'brand, T: ?Sized + GhostOrd<'brand>> Ord for WithToken<'a, 'brand, T> {
+    fn cmp(&self, other: &Self) -> Ordering { self.value().ghost_cmp(other.value(), self.token()) }
+}
+
+impl<'a, 'brand, T: ?Sized + GhostHash<'brand>> Hash for WithToken<'a, 'brand, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.value().ghost_hash(state, self.token()) }
+}
+
+//
+//  Helpers
+//
+
+//  Compares two sequences for equality, element by element.
+pub(crate) fn iter_eq<'brand, I>(left: I, right: I, token: &GhostToken<'brand>) -> bool
+where
+    I: ExactSizeIterator,
+    I::Item: GhostPartialEq<'brand>,
+{
+    left.len() == right.len() && left.zip(right).all(|(l, r)| l.ghost_eq(&r, token))
+}
+
+//  Compares two sequences lexicographically.
+pub(crate) fn iter_partial_cmp<'brand, I>(mut left: I, mut right: I, token: &GhostToken<'brand>) -> Option<Ordering>
+where
+    I: Iterator,
+    I::Item: GhostPartialOrd<'brand>,
+{
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return Some(Ordering::Equal),
+            (None, Some(_)) => return Some(Ordering::Less),
+            (Some(_), None) => return Some(Ordering::Greater),
+            (Some(l), Some(r)) => match l.ghost_partial_cmp(&r, token) {
+                Some(Ordering::Equal) => (),
+                non_eq => return non_eq,
+            },
+        }
+    }
+}
+
+//  Compares two sequences lexicographically.
+pub(crate) fn iter_cmp<'brand, I>(mut left: I, mut right: I, token: &GhostToken<'brand>) -> Ordering
+where
+    I: Iterator,
+    I::Item: GhostOrd<'brand>,
+{
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) => match l.ghost_cmp(&r, token) {
+                Ordering::Equal => (),
+                non_eq => return non_eq,
+            },
+        }
+    }
+}
+
+//  Hashes a sequence, prefixed by its length so that `[[1], [2]]` and `[[1, 2]]` hash differently.
+pub(crate) fn iter_hash<'brand, I, H>(iter: I, state: &mut H, token: &GhostToken<'brand>)
+where
+    I: ExactSizeIterator,
+    I::Item: GhostHash<'brand>,
+    H: Hasher,
+{
+    state.write_usize(iter.len());
+
+    for item in iter {
+        item.ghost_hash(state, token);
+    }
+}
+
+//
+//  Implementations
+//
+
+macro_rules! brand_free_eq {
+    ($($t:ty),*) => {
+        $(
+            impl<'brand> GhostPartialEq<'brand> for $t {
+                #[inline(always)]
+                fn ghost_eq(&self, other: &Self, _: &GhostToken<'brand>) -> bool { self == other }
+            }
+
+            impl<'brand> GhostPartialOrd<'brand> for $t {
+                #[inline(always)]
+                fn ghost_partial_cmp(&self, other: &Self, _: &GhostToken<'brand>) -> Option<Ordering> {
+                    self.partial_cmp(other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! brand_free_ord {
+    ($($t:ty),*) => {
+        $(
+            brand_free_eq!($t);
+
+            impl<'brand> GhostEq<'brand> for $t {}
+
+            impl<'brand> GhostOrd<'brand> for $t {
+                #[inline(always)]
+                fn ghost_cmp(&self, other: &Self, _: &GhostToken<'brand>) -> Ordering { self.cmp(other) }
+            }
+
+            impl<'brand> GhostHash<'brand> for $t {
+                #[inline(always)]
+                fn ghost_hash<H: Hasher>(&self, state: &mut H, _: &GhostToken<'brand>) { self.hash(state) }
+            }
+        )*
+    };
+}
+
+brand_free_eq!(f32, f64);
+
+brand_free_ord!((), bool, char, i8, i16, i32, i64, i128, isize, str, u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "alloc")]
+brand_free_ord!(String);
+
+//  Forwards all traits through a pointer-like type.
+macro_rules! forward {
+    ($([$($g:tt)*] $t:ty),*) => {
+        $(
+            impl<'brand, $($g)*> GhostPartialEq<'brand> for $t
+            where
+                T: GhostPartialEq<'brand>,
+            {
+                #[inline(always)]
+                fn ghost_eq(&self, other: &Self, token: &GhostToken<'brand>) -> bool {
+                    (**self).ghost_eq(&**other, token)
+                }
+            }
+
+            impl<'brand, $($g)*> GhostEq<'brand> for $t where T: GhostEq<'brand> {}
+
+            impl<'brand, $($g)*> GhostPartialOrd<'brand> for $t
+            where
+                T: GhostPartialOrd<'brand>,
+            {
+                #[inline(always)]
+                fn ghost_partial_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Option<Ordering> {
+                    (**self).ghost_partial_cmp(&**other, token)
+                }
+            }
+
+            impl<'brand, $($g)*> GhostOrd<'brand> for $t
+            where
+                T: GhostOrd<'brand>,
+            {
+                #[inline(always)]
+                fn ghost_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Ordering {
+                    (**self).ghost_cmp(&**other, token)
+                }
+            }
+
+            impl<'brand, $($g)*> GhostHash<'brand> for $t
+            where
+                T: GhostHash<'brand>,
+            {
+                #[inline(always)]
+                fn ghost_hash<H: Hasher>(&self, state: &mut H, token: &GhostToken<'brand>) {
+                    (**self).ghost_hash(state, token)
+                }
+            }
+        )*
+    };
+}
+
+forward!(['a, T: ?Sized] &'a T);
+
+#[cfg(feature = "alloc")]
+forward!([T: ?Sized] Box<T>);
+
+//  Forwards all traits through a slice-like type.
+macro_rules! sequence {
+    ($([$($g:tt)*] $t:ty),*) => {
+        $(
+            impl<'brand, $($g)*> GhostPartialEq<'brand> for $t
+            where
+                T: GhostPartialEq<'brand>,
+            {
+                fn ghost_eq(&self, other: &Self, token: &GhostToken<'brand>) -> bool {
+                    iter_eq(self.iter(), other.iter(), token)
+                }
+            }
+
+            impl<'brand, $($g)*> GhostEq<'brand> for $t where T: GhostEq<'brand> {}
+
+            impl<'brand, $($g)*> GhostPartialOrd<'brand> for $t
+            where
+                T: GhostPartialOrd<'brand>,
+            {
+                fn ghost_partial_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Option<Ordering> {
+                    iter_partial_cmp(self.iter(), other.iter(), token)
+                }
+            }
+
+            impl<'brand, $($g)*> GhostOrd<'brand> for $t
+            where
+                T: GhostOrd<'brand>,
+            {
+                fn ghost_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Ordering {
+                    iter_cmp(self.iter(), other.iter(), token)
+                }
+            }
+
+            impl<'brand, $($g)*> GhostHash<'brand> for $t
+            where
+                T: GhostHash<'brand>,
+            {
+                fn ghost_hash<H: Hasher>(&self, state: &mut H, token: &GhostToken<'brand>) {
+                    iter_hash(self.iter(), state, token)
+                }
+            }
+        )*
+    };
+}
+
+sequence!([T] [T], [T, const N: usize] [T; N]);
+
+#[cfg(feature = "alloc")]
+sequence!([T] Vec<T>);
+
+//  `Option` is ordered `None` first, as per the standard library.
+impl<'brand, T: GhostPartialEq<'brand>> GhostPartialEq<'brand> for Option<T> {
+    fn ghost_eq(&self, other: &Self, token: &GhostToken<'brand>) -> bool {
+        match (self, other) {
+            (Some(l), Some(r)) => l.ghost_eq(r, token),
+            (l, r) => l.is_none() && r.is_none(),
+        }
+    }
+}
+
+impl<'brand, T: GhostEq<'brand>> GhostEq<'brand> for Option<T> {}
+
+impl<'brand, T: GhostPartialOrd<'brand>> GhostPartialOrd<'brand> for Option<T> {
+    fn ghost_partial_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Option<Ordering> {
+        match (self, other) {
+            (Some(l), Some(r)) => l.ghost_partial_cmp(r, token),
+            (l, r) => Some(l.is_some().cmp(&r.is_some())),
+        }
+    }
+}
+
+impl<'brand, T: GhostOrd<'brand>> GhostOrd<'brand> for Option<T> {
+    fn ghost_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Ordering {
+        match (self, other) {
+            (Some(l), Some(r)) => l.ghost_cmp(r, token),
+            (l, r) => l.is_some().cmp(&r.is_some()),
+        }
+    }
+}
+
+impl<'brand, T: GhostHash<'brand>> GhostHash<'brand> for Option<T> {
+    fn ghost_hash<H: Hasher>(&self, state: &mut H, token: &GhostToken<'brand>) {
+        match self {
+            Some(value) => {
+                state.write_u8(1);
+                value.ghost_hash(state, token);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+
+//  `GhostCell` is transparent: its content is compared and hashed as if it were not wrapped.
+impl<'brand, T: GhostPartialEq<'brand>> GhostPartialEq<'brand> for GhostCell<'brand, T> {
+    fn ghost_eq(&self, other: &Self, token: &GhostToken<'brand>) -> bool {
+        self.borrow(token).ghost_eq(other.borrow(token), token)
+    }
+}
+
+impl<'brand, T: GhostEq<'brand>> GhostEq<'brand> for GhostCell<'brand, T> {}
+
+impl<'brand, T: GhostPartialOrd<'brand>> GhostPartialOrd<'brand> for GhostCell<'brand, T> {
+    fn ghost_partial_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Option<Ordering> {
+        self.borrow(token).ghost_partial_cmp(other.borrow(token), token)
+    }
+}
+
+impl<'brand, T: GhostOrd<'brand>> GhostOrd<'brand> for GhostCell<'brand, T> {
+    fn ghost_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Ordering {
+        self.borrow(token).ghost_cmp(other.borrow(token), token)
+    }
+}
+
+impl<'brand, T: GhostHash<'brand>> GhostHash<'brand> for GhostCell<'brand, T> {
+    fn ghost_hash<H: Hasher>(&self, state: &mut H, token: &GhostToken<'brand>) {
+        self.borrow(token).ghost_hash(state, token)
+    }
+}
+
+macro_rules! tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<'brand, $($name: GhostPartialEq<'brand>),+> GhostPartialEq<'brand> for ($($name,)+) {
+            fn ghost_eq(&self, other: &Self, token: &GhostToken<'brand>) -> bool {
+                $(self.$index.ghost_eq(&other.$index, token))&&+
+            }
+        }
+
+        impl<'brand, $($name: GhostEq<'brand>),+> GhostEq<'brand> for ($($name,)+) {}
+
+        impl<'brand, $($name: GhostPartialOrd<'brand>),+> GhostPartialOrd<'brand> for ($($name,)+) {
+            fn ghost_partial_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Option<Ordering> {
+                $(
+                    match self.$index.ghost_partial_cmp(&other.$index, token) {
+                        Some(Ordering::Equal) => (),
+                        non_eq => return non_eq,
+                    }
+                )+
+
+                Some(Ordering::Equal)
+            }
+        }
+
+        impl<'brand, $($name: GhostOrd<'brand>),+> GhostOrd<'brand> for ($($name,)+) {
+            fn ghost_cmp(&self, other: &Self, token: &GhostToken<'brand>) -> Ordering {
+                $(
+                    match self.$index.ghost_cmp(&other.$index, token) {
+                        Ordering::Equal => (),
+                        non_eq => return non_eq,
+                    }
+                )+
+
+                Ordering::Equal
+            }
+        }
+
+        impl<'brand, $($name: GhostHash<'brand>),+> GhostHash<'brand> for ($($name,)+) {
+            fn ghost_hash<S: Hasher>(&self, state: &mut S, token: &GhostToken<'brand>) {
+                $(self.$index.ghost_hash(state, token);)+
+            }
+        }
+    };
+}
+
+tuple!(A 0);
+tuple!(A 0, B 1);
+tuple!(A 0, B 1, C 2);
+tuple!(A 0, B 1, C 2, D 3);
+tuple!(A 0, B 1, C 2, D 3, E 4);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
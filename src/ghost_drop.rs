@@ -0,0 +1,381 @@
+//  Token-aware destructors.
+//
+//  `Drop::drop` receives no token, hence types whose teardown requires one -- to unlink nodes, or join `StaticRc`
+//  halves -- cannot clean-up after themselves. A `GhostSea` holds its own token, however, and can lend it.
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    mem::ManuallyDrop,
+};
+
+use ghost_cell::GhostToken;
+
+#[cfg(feature = "alloc")]
+use crate::GhostClone;
+
+use crate::{
+    GhostDebug, GhostEq, GhostHash, GhostOrd, GhostOutput, GhostPartialEq, GhostPartialOrd, GhostProject, GhostSea,
+    GhostSeaMut, GhostSeaRef,
+};
+
+//  Forwards the operations of `GhostSea` borrowing it, listed as `ref|mut name['a, generics](arguments) -> result
+//  where [bounds];`, `'a` being the lifetime of the borrow of `self`.
+//
+//  A turbofish may follow `name`, for the generic parameters the forwarded call cannot infer.
+macro_rules! forward {
+    () => {};
+    (ref $name:ident $(::<$($turbofish:ident),*>)? [$lt:lifetime, $($g:tt)*]($($arg:ident: $arg_ty:ty),*) -> $result:ty
+        where [$($bounds:tt)*]; $($rest:tt)*) => {
+        #[doc = concat!("Forwards to `GhostSea::", stringify!($name), "`.")]
+        #[inline(always)]
+        pub fn $name<$lt, $($g)*>(&$lt self, $($arg: $arg_ty),*) -> $result
+        where
+            $($bounds)*
+        {
+            self.0.$name$(::<$($turbofish),*>)?($($arg),*)
+        }
+
+        forward! { $($rest)* }
+    };
+    (mut $name:ident $(::<$($turbofish:ident),*>)? [$lt:lifetime, $($g:tt)*]($($arg:ident: $arg_ty:ty),*) -> $result:ty
+        where [$($bounds:tt)*]; $($rest:tt)*) => {
+        #[doc = concat!("Forwards to `GhostSea::", stringify!($name), "`.")]
+        #[inline(always)]
+        pub fn $name<$lt, $($g)*>(&$lt mut self, $($arg: $arg_ty),*) -> $result
+        where
+            $($bounds)*
+        {
+            self.0.$name$(::<$($turbofish),*>)?($($arg),*)
+        }
+
+        forward! { $($rest)* }
+    };
+}
+
+/// Destructor requiring the token, invoked by `GhostDropSea` upon drop.
+///
+/// Like `GhostProject`, it is implemented on the un-branded type, and operates on the branded type.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostDrop, GhostDropSea, GhostProject, GhostToken};
+///
+/// struct Names<'brand>(GhostCell<'brand, Vec<String>>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Names<'static> {
+///     type Branded = Names<'id>;
+/// }
+///
+/// impl<'id> GhostDrop<'id> for Names<'static> {
+///     fn ghost_drop(this: &mut Names<'id>, token: &mut GhostToken<'id>) {
+///         this.0.borrow_mut(token).clear();
+///     }
+/// }
+///
+/// let mut sea = GhostDropSea::new(Names(GhostCell::new(vec!["Alice".to_string()])));
+///
+/// sea.apply_mut(|names, token| names.0.borrow_mut(token).push("Bob".to_string()));
+/// ```
+pub trait GhostDrop<'id>: GhostProject<'id> {
+    /// Tears down `this`, prior to it being dropped.
+    fn ghost_drop(this: &mut Self::Branded, token: &mut GhostToken<'id>);
+}
+
+/// A `GhostSea` which invokes `GhostDrop::ghost_drop` on its content when dropped.
+///
+/// The operations of `GhostSea` borrowing it are forwarded, rather than available via `Deref`: a `&mut GhostSea` would
+/// let it be swapped out, and a `&GhostSea` would let it be cloned into a plain `GhostSea`, either escaping
+/// `GhostDrop::ghost_drop`. For the operations consuming the `GhostSea`, `into_sea` first releases it.
+///
+/// ```compile_fail,E0614
+/// use ghost_sea::{GhostCell, GhostDrop, GhostDropSea, GhostProject, GhostSea, GhostToken};
+///
+/// struct Names<'brand>(GhostCell<'brand, Vec<String>>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Names<'static> {
+///     type Branded = Names<'id>;
+/// }
+///
+/// impl<'id> GhostDrop<'id> for Names<'static> {
+///     fn ghost_drop(this: &mut Names<'id>, token: &mut GhostToken<'id>) {
+///         this.0.borrow_mut(token).clear();
+///     }
+/// }
+///
+/// let mut sea = GhostDropSea::new(Names(GhostCell::new(vec!["Alice".to_string()])));
+///
+/// let escaped = std::mem::replace(&mut *sea, GhostSea::new(Names(GhostCell::new(Vec::new()))));
+/// ```
+///
+/// Neither do the guards returned by `map_ref` and `filter_map_ref` lead back to the `GhostSea`:
+///
+/// ```compile_fail,E0599
+/// use ghost_sea::{GhostCell, GhostDrop, GhostDropSea, GhostProject, GhostToken};
+///
+/// struct Names<'brand>(GhostCell<'brand, Vec<String>>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Names<'static> {
+///     type Branded = Names<'id>;
+/// }
+///
+/// impl<'id> GhostDrop<'id> for Names<'static> {
+///     fn ghost_drop(this: &mut Names<'id>, token: &mut GhostToken<'id>) {
+///         this.0.borrow_mut(token).clear();
+///     }
+/// }
+///
+/// let sea = GhostDropSea::new(Names(GhostCell::new(vec!["Alice".to_string()])));
+///
+/// let names = sea.map_ref(|names, token| names.0.borrow(token));
+/// let escaped = names.sea().clone();
+/// ```
+pub struct GhostDropSea<T>(ManuallyDrop<GhostSea<T>>)
+where
+    T: for<'id> GhostDrop<'id>;
+
+impl<T> GhostDropSea<T>
+where
+    T: for<'id> GhostDrop<'id>,
+{
+    /// Creates a new instance.
+    #[inline(always)]
+    pub fn new(value: T) -> Self { Self(ManuallyDrop::new(GhostSea::new(value))) }
+
+    /// Returns the `GhostSea` contained within, which will no longer invoke `GhostDrop::ghost_drop`.
+    #[inline(always)]
+    pub fn into_sea(self) -> GhostSea<T> {
+        let mut this = ManuallyDrop::new(self);
+
+        //  Safety:
+        //  -   `this` is never dropped, hence the sea is moved out exactly once.
+        unsafe { ManuallyDrop::take(&mut this.0) }
+    }
+
+    /// Returns the value contained within, which will no longer be subject to `GhostDrop::ghost_drop`.
+    #[inline(always)]
+    pub fn into_inner(self) -> T { self.into_sea().into_inner() }
+
+    forward! {
+        ref apply_ref['a, R, F](fun: F) -> R
+        where
+            [F: for<'id> FnOnce(&'a <T as GhostProject<'id>>::Branded, &'a GhostToken<'id>) -> R];
+
+        ref apply_ref_with::<O, F>['a, O, F](fun: F) -> O::Of<'a>
+        where
+            [
+                O: GhostOutput + 'a,
+                F: for<'id> FnOnce(&'id <T as GhostProject<'id>>::Branded, &'id GhostToken<'id>) -> O::Of<'id>,
+            ];
+
+        ref map_ref['a, U: ?Sized, F](fun: F) -> GhostSeaRef<'a, T, U>
+        where
+            [F: for<'id> FnOnce(&'a <T as GhostProject<'id>>::Branded, &'a GhostToken<'id>) -> &'a U];
+
+        ref filter_map_ref['a, U: ?Sized, F](fun: F) -> Option<GhostSeaRef<'a, T, U>>
+        where
+            [F: for<'id> FnOnce(&'a <T as GhostProject<'id>>::Branded, &'a GhostToken<'id>) -> Option<&'a U>];
+
+        ref combine_ref['a, R, O, F](other: GhostSea<O>, fun: F) -> R
+        where
+            [
+                for<'id> O: GhostProject<'id>,
+                for<'id> F: FnOnce(
+                    &'a <T as GhostProject<'id>>::Branded,
+                    <O as GhostProject<'id>>::Branded,
+                    &'a GhostToken<'id>,
+                ) -> R,
+            ];
+
+        ref zip_ref['a, R, O, F](other: &'a GhostSea<O>, fun: F) -> R
+        where
+            [
+                for<'id> O: GhostProject<'id>,
+                F: for<'a1, 'a2> FnOnce(
+                    &'a <T as GhostProject<'a1>>::Branded,
+                    &'a GhostToken<'a1>,
+                    &'a <O as GhostProject<'a2>>::Branded,
+                    &'a GhostToken<'a2>,
+                ) -> R,
+            ];
+
+        ref zip_ref_mut['a, R, O, F](other: &'a mut GhostSea<O>, fun: F) -> R
+        where
+            [
+                for<'id> O: GhostProject<'id>,
+                F: for<'a1, 'a2> FnOnce(
+                    &'a <T as GhostProject<'a1>>::Branded,
+                    &'a GhostToken<'a1>,
+                    &'a mut <O as GhostProject<'a2>>::Branded,
+                    &'a mut GhostToken<'a2>,
+                ) -> R,
+            ];
+
+        mut apply_mut['a, R, F](fun: F) -> R
+        where
+            [F: for<'id> FnOnce(&'a mut <T as GhostProject<'id>>::Branded, &'a mut GhostToken<'id>) -> R];
+
+        mut apply_mut_with::<O, F>['a, O, F](fun: F) -> O::Of<'a>
+        where
+            [
+                O: GhostOutput + 'a,
+                F: for<'id> FnOnce(
+                    &'id mut <T as GhostProject<'id>>::Branded,
+                    &'id mut GhostToken<'id>,
+                ) -> O::Of<'id>,
+            ];
+
+        mut map_mut['a, U: ?Sized, F](fun: F) -> GhostSeaMut<'a, T, U>
+        where
+            [F: for<'id> FnOnce(&'a mut <T as GhostProject<'id>>::Branded, &'a mut GhostToken<'id>) -> &'a mut U];
+
+        mut filter_map_mut['a, U: ?Sized, F](fun: F) -> Option<GhostSeaMut<'a, T, U>>
+        where
+            [
+                F: for<'id> FnOnce(
+                    &'a mut <T as GhostProject<'id>>::Branded,
+                    &'a mut GhostToken<'id>,
+                ) -> Option<&'a mut U>,
+            ];
+
+        mut combine_mut['a, R, O, F](other: GhostSea<O>, fun: F) -> R
+        where
+            [
+                for<'id> O: GhostProject<'id>,
+                for<'id> F: FnOnce(
+                    &'a mut <T as GhostProject<'id>>::Branded,
+                    <O as GhostProject<'id>>::Branded,
+                    &'a mut GhostToken<'id>,
+                ) -> R,
+            ];
+
+        mut zip_mut['a, R, O, F](other: &'a mut GhostSea<O>, fun: F) -> R
+        where
+            [
+                for<'id> O: GhostProject<'id>,
+                F: for<'a1, 'a2> FnOnce(
+                    &'a mut <T as GhostProject<'a1>>::Branded,
+                    &'a mut GhostToken<'a1>,
+                    &'a mut <O as GhostProject<'a2>>::Branded,
+                    &'a mut GhostToken<'a2>,
+                ) -> R,
+            ];
+
+        mut zip_mut_ref['a, R, O, F](other: &'a GhostSea<O>, fun: F) -> R
+        where
+            [
+                for<'id> O: GhostProject<'id>,
+                F: for<'a1, 'a2> FnOnce(
+                    &'a mut <T as GhostProject<'a1>>::Branded,
+                    &'a mut GhostToken<'a1>,
+                    &'a <O as GhostProject<'a2>>::Branded,
+                    &'a GhostToken<'a2>,
+                ) -> R,
+            ];
+    }
+
+    //  Forwards to `GhostSea::parts`.
+    //
+    //  #   Safety
+    //
+    //  -   As per `GhostSea::parts`.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub(crate) unsafe fn parts(&self) -> (&T, &GhostToken<'static>) { self.0.parts() }
+
+    //  Forwards to `GhostSea::parts_mut`.
+    //
+    //  #   Safety
+    //
+    //  -   As per `GhostSea::parts_mut`.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub(crate) unsafe fn parts_mut(&mut self) -> (&mut T, &mut GhostToken<'static>) { self.0.parts_mut() }
+}
+
+impl<T> Default for GhostDropSea<T>
+where
+    T: for<'id> GhostDrop<'id> + Default,
+{
+    fn default() -> Self { Self::new(T::default()) }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Clone for GhostDropSea<T>
+where
+    T: for<'id> GhostDrop<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostClone<'id>,
+{
+    fn clone(&self) -> Self { Self(self.0.clone()) }
+}
+
+impl<T> fmt::Debug for GhostDropSea<T>
+where
+    T: for<'id> GhostDrop<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostDebug<'id>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.0.fmt(f) }
+}
+
+impl<T> PartialEq for GhostDropSea<T>
+where
+    T: for<'id> GhostDrop<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostPartialEq<'id>,
+{
+    fn eq(&self, other: &Self) -> bool { self.0.eq(&other.0) }
+}
+
+impl<T> Eq for GhostDropSea<T>
+where
+    T: for<'id> GhostDrop<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostEq<'id>,
+{
+}
+
+impl<T> PartialOrd for GhostDropSea<T>
+where
+    T: for<'id> GhostDrop<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostPartialOrd<'id>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { self.0.partial_cmp(&other.0) }
+}
+
+impl<T> Ord for GhostDropSea<T>
+where
+    T: for<'id> GhostDrop<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostOrd<'id>,
+{
+    fn cmp(&self, other: &Self) -> Ordering { self.0.cmp(&other.0) }
+}
+
+impl<T> Hash for GhostDropSea<T>
+where
+    T: for<'id> GhostDrop<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostHash<'id>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) { self.0.hash(state) }
+}
+
+impl<T> Drop for GhostDropSea<T>
+where
+    T: for<'id> GhostDrop<'id>,
+{
+    fn drop(&mut self) {
+        //  Drops the sea even if `ghost_drop` panics.
+        struct DropGuard<'a, T>(&'a mut ManuallyDrop<GhostSea<T>>);
+
+        impl<'a, T> Drop for DropGuard<'a, T> {
+            fn drop(&mut self) {
+                //  Safety:
+                //  -   The sea is dropped exactly once, as `GhostDropSea` is itself being dropped.
+                unsafe { ManuallyDrop::drop(self.0) }
+            }
+        }
+
+        let guard = DropGuard(&mut self.0);
+
+        guard.0.apply_mut(|ghost, token| T::ghost_drop(ghost, token));
+    }
+}
@@ -0,0 +1,623 @@
+//  Several members sharing one brand, and one token.
+//
+//  A `GhostFleet` is a `GhostSea` over a set of members, whose cells may reference one another. The members are either
+//  a tuple, added to and removed from its end, changing the type of the fleet accordingly, or a struct declared with
+//  `ghost_members!`, accessed by name.
+//
+//  Positional members are removed either last, through `without`, or at any position, through `without_at` and
+//  `GhostRemove`, implemented for each position of each arity of tuples. Named members cannot be added nor removed, as
+//  the resulting struct would need declaring: reshaping them is left to `GhostSea::map`.
+//
+//  Removing a member never releases it as a `'static` value, as its cells may be shared with the remaining members:
+//  it is instead handed, branded, to a callback for disposal.
+
+use core::ops::{Deref, DerefMut};
+
+use ghost_cell::GhostToken;
+
+use crate::{GhostProject, GhostSea};
+
+/// A `GhostSea` over a set of members, sharing one brand and one token.
+///
+/// The members are either a tuple, or a struct declared with `ghost_members!`. Either way, `apply_ref` and `apply_mut`
+/// hand out one borrow per member, as per `GhostMembers`, alongside the token. All other operations of `GhostSea` are
+/// available via `Deref` and `DerefMut`.
+///
+/// #   Adding and removing members
+///
+/// Tuple members are positional: `with` and `with_branded` add a member last, `without` removes the last member, and
+/// `without_at` removes the member at any position.
+///
+/// Named members, declared with `ghost_members!`, can neither be added nor removed, as each set of members is a struct
+/// of its own: reshape the underlying `GhostSea` with `GhostSea::map` instead, as shown in `ghost_members!`.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{ghost_fleet, GhostCell, GhostFleet};
+///
+/// let mut fleet = ghost_fleet!([GhostCell::new(3u32), GhostCell::new(1), GhostCell::new(2)], 0usize);
+///
+/// fleet.apply_mut(|(values, least), token| {
+///     *least = (0..values.len()).min_by_key(|i| *values[*i].borrow(token)).unwrap();
+/// });
+///
+/// //  Adds a member computed from the existing ones.
+/// let fleet: GhostFleet<(_, _, GhostCell<u32>)> =
+///     fleet.with_branded(|(values, least), token| GhostCell::new(*values[*least].borrow(token) * 10));
+///
+/// assert_eq!(10, fleet.apply_ref(|(_, _, scaled), token| *scaled.borrow(token)));
+///
+/// //  Removes it, disposing of it.
+/// let (fleet, scaled) = fleet.without(|_, scaled, _| scaled.into_inner());
+///
+/// assert_eq!(10, scaled);
+/// assert_eq!(1, fleet.apply_ref(|(_, least), _| *least));
+/// ```
+///
+/// `without` removes the last member, handing it to a callback alongside those remaining:
+///
+/// ```
+/// use ghost_sea::{ghost_fleet, GhostCell, GhostFleet};
+///
+/// let fleet = ghost_fleet!(GhostCell::new(1u32), GhostCell::new(2u32), GhostCell::new(3u32));
+///
+/// let (fleet, three) = fleet.without(|_, three, _| three.into_inner());
+/// let (fleet, two) = fleet.without(|(one,), two, token| *one.borrow(token) + two.into_inner());
+/// let (fleet, one) = fleet.without(|(), one, _| one.into_inner());
+///
+/// assert_eq!((1, 3, 3), (one, two, three));
+///
+/// let _: GhostFleet<()> = fleet;
+/// ```
+///
+/// Members created by `with_branded` are branded, hence may be built from the cells of the existing members:
+///
+/// ```
+/// use ghost_sea::{ghost_fleet, GhostCell, GhostFleet};
+///
+/// let fleet = ghost_fleet!(GhostCell::new(2u32));
+///
+/// let fleet: GhostFleet<(_, GhostCell<u32>)> =
+///     fleet.with_branded(|(two,), token| GhostCell::new(*two.borrow(token) * 2));
+/// let fleet: GhostFleet<(_, _, GhostCell<u32>)> =
+///     fleet.with_branded(|(two, four), token| GhostCell::new(*two.borrow(token) * *four.borrow(token)));
+///
+/// assert_eq!(14, fleet.apply_ref(|(a, b, c), token| *a.borrow(token) + *b.borrow(token) + *c.borrow(token)));
+/// ```
+///
+/// Any other member is removed by position:
+///
+/// ```
+/// use ghost_sea::{ghost_fleet, GhostCell, GhostFleet};
+///
+/// let fleet = ghost_fleet!(GhostCell::new(1u32), GhostCell::new(2u32), GhostCell::new(3u32));
+///
+/// let (fleet, two) = fleet.without_at::<1, _, _>(|(_, three), two, token| {
+///     let two = two.into_inner();
+///
+///     *three.borrow_mut(token) += two;
+///
+///     two
+/// });
+///
+/// assert_eq!(2, two);
+/// assert_eq!((1, 5), fleet.apply_ref(|(one, three), token| (*one.borrow(token), *three.borrow(token))));
+///
+/// let (fleet, one) = fleet.without_at::<0, _, _>(|_, one, _| one.into_inner());
+///
+/// assert_eq!(1, one);
+///
+/// let _: GhostFleet<(GhostCell<u32>,)> = fleet;
+/// ```
+///
+/// Named members are declared with `ghost_members!`, see its documentation.
+pub struct GhostFleet<M>(GhostSea<M>)
+where
+    M: for<'id> GhostProject<'id>;
+
+impl GhostFleet<()> {
+    /// Creates a new instance, without any member.
+    #[inline(always)]
+    pub fn new() -> Self { Self(GhostSea::new(())) }
+}
+
+impl<M> GhostFleet<M>
+where
+    M: for<'id> GhostProject<'id>,
+{
+    /// Creates a new instance from `members`.
+    #[inline(always)]
+    pub fn from_members(members: M) -> Self { Self(GhostSea::new(members)) }
+
+    /// Creates a new instance from the members contained within `sea`.
+    #[inline(always)]
+    pub fn from_sea(sea: GhostSea<M>) -> Self { Self(sea) }
+
+    /// Returns the `GhostSea` contained within.
+    #[inline(always)]
+    pub fn into_sea(self) -> GhostSea<M> { self.0 }
+
+    /// Removes the member at position `N`, returning the shrunk fleet, and the result of `fun`.
+    ///
+    /// As the cells of the removed member may be shared with the remaining members, it is handed branded to `fun`,
+    /// alongside the remaining members, for disposal.
+    #[inline(always)]
+    pub fn without_at<const N: usize, R, Fun>(self, fun: Fun) -> (GhostFleet<<M as GhostRemove<N>>::Remaining>, R)
+    where
+        M: GhostRemove<N>,
+        for<'id> Fun: FnOnce(
+            &mut <<M as GhostRemove<N>>::Remaining as GhostProject<'id>>::Branded,
+            <<M as GhostRemove<N>>::Removed as GhostProject<'id>>::Branded,
+            &mut GhostToken<'id>,
+        ) -> R,
+    {
+        let (sea, result) = self.0.map_split(|members, token| {
+            let (mut members, member) = M::remove(members);
+
+            let result = fun(&mut members, member, token);
+
+            (members, result)
+        });
+
+        (GhostFleet(sea), result)
+    }
+
+    /// Apply the provided function, and return its result.
+    ///
+    /// The function is called with one shared borrow per member, and the token, as per `GhostSea::apply_ref`.
+    #[inline(always)]
+    pub fn apply_ref<'a, R, F>(&'a self, fun: F) -> R
+    where
+        for<'id> &'a <M as GhostProject<'id>>::Branded: GhostMembers,
+        F: for<'id> FnOnce(<&'a <M as GhostProject<'id>>::Branded as GhostMembers>::Members, &'a GhostToken<'id>) -> R,
+    {
+        self.0.apply_ref(|members, token| fun(members.members(), token))
+    }
+
+    /// Apply the provided function, and return its result.
+    ///
+    /// The function is called with one exclusive borrow per member, and the token, as per `GhostSea::apply_mut`.
+    #[inline(always)]
+    pub fn apply_mut<'a, R, F>(&'a mut self, fun: F) -> R
+    where
+        for<'id> &'a mut <M as GhostProject<'id>>::Branded: GhostMembers,
+        F: for<'id> FnOnce(
+            <&'a mut <M as GhostProject<'id>>::Branded as GhostMembers>::Members,
+            &'a mut GhostToken<'id>,
+        ) -> R,
+    {
+        self.0.apply_mut(|members, token| fun(members.members(), token))
+    }
+}
+
+impl Default for GhostFleet<()> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<M> Deref for GhostFleet<M>
+where
+    M: for<'id> GhostProject<'id>,
+{
+    type Target = GhostSea<M>;
+
+    fn deref(&self) -> &GhostSea<M> { &self.0 }
+}
+
+impl<M> DerefMut for GhostFleet<M>
+where
+    M: for<'id> GhostProject<'id>,
+{
+    fn deref_mut(&mut self) -> &mut GhostSea<M> { &mut self.0 }
+}
+
+/// Splits a borrow of the branded members of a `GhostFleet` into one borrow per member.
+///
+/// It is implemented for borrows of tuples, yielding tuples of borrows, and generated by `ghost_members!` for borrows
+/// of named members, yielding structs of borrows.
+pub trait GhostMembers {
+    /// One borrow per member.
+    type Members;
+
+    /// Splits `self` into one borrow per member.
+    fn members(self) -> Self::Members;
+}
+
+/// Removes the member at position `N` of a tuple of members.
+///
+/// It is implemented for each position of tuples of up to 12 members, and used by `GhostFleet::without_at`.
+pub trait GhostRemove<const N: usize>: for<'id> GhostProject<'id> {
+    /// The remaining members.
+    type Remaining: for<'id> GhostProject<'id>;
+
+    /// The removed member.
+    type Removed: for<'id> GhostProject<'id>;
+
+    /// Splits the branded `members` into the remaining members, and the removed member.
+    fn remove<'id>(
+        members: <Self as GhostProject<'id>>::Branded,
+    ) -> (<Self::Remaining as GhostProject<'id>>::Branded, <Self::Removed as GhostProject<'id>>::Branded);
+}
+
+/// Declares a struct of named members for use with `GhostFleet`.
+///
+/// The invocation declares the struct itself, branded with a single lifetime, followed by the names of the structs
+/// of shared and exclusive borrows of its members:
+///
+/// -   The struct implements `GhostProject` and `GhostUnproject`, each of its fields being checked to only project
+///     the brand, and to contain no `GhostToken`, as per `#[derive(GhostProject)]`.
+/// -   The structs of borrows mirror its fields, and are handed out by `GhostFleet::apply_ref` and
+///     `GhostFleet::apply_mut` via `GhostMembers`.
+///
+/// The structs of borrows are parameterized by `'a`, the lifetime of the borrow, then the brand, which therefore
+/// cannot itself be named `'a`.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{ghost_members, GhostCell, GhostFleet};
+///
+/// ghost_members! {
+///     /// A pool of values, alongside whether each is free.
+///     pub struct Pool<'brand> {
+///         /// The values.
+///         pub values: [GhostCell<'brand, u32>; 3],
+///         /// Whether each value is free.
+///         pub free: [bool; 3],
+///     }
+///
+///     ref PoolRef;
+///     mut PoolMut;
+/// }
+///
+/// let mut fleet = GhostFleet::from_members(Pool {
+///     values: [GhostCell::new(0), GhostCell::new(0), GhostCell::new(0)],
+///     free: [true; 3],
+/// });
+///
+/// fleet.apply_mut(|PoolMut { values, free }, token| {
+///     let index = free.iter().position(|free| *free).unwrap();
+///
+///     free[index] = false;
+///     *values[index].borrow_mut(token) = 42;
+/// });
+///
+/// let used: u32 = fleet.apply_ref(|pool, token| {
+///     pool.values.iter().zip(pool.free).filter(|(_, free)| !*free).map(|(value, _)| *value.borrow(token)).sum()
+/// });
+///
+/// assert_eq!(42, used);
+/// ```
+///
+/// Named members can neither be added nor removed, each set of members being a struct of its own; instead, the
+/// underlying `GhostSea` is reshaped into another set:
+///
+/// ```
+/// use ghost_sea::{ghost_members, GhostCell, GhostFleet};
+///
+/// ghost_members! {
+///     struct Graph<'brand> {
+///         nodes: Vec<GhostCell<'brand, u32>>,
+///         index: Vec<usize>,
+///     }
+///
+///     ref GraphRef;
+///     mut GraphMut;
+/// }
+///
+/// ghost_members! {
+///     struct Nodes<'brand> {
+///         nodes: Vec<GhostCell<'brand, u32>>,
+///     }
+///
+///     ref NodesRef;
+///     mut NodesMut;
+/// }
+///
+/// let fleet = GhostFleet::from_members(Graph { nodes: vec![GhostCell::new(1), GhostCell::new(2)], index: vec![1] });
+///
+/// let fleet: GhostFleet<Nodes<'static>> = GhostFleet::from_sea(fleet.into_sea().map(|Graph { nodes, index }, token| {
+///     *nodes[index[0]].borrow_mut(token) += 40;
+///
+///     Nodes { nodes }
+/// }));
+///
+/// let sum: u32 = fleet.apply_ref(|NodesRef { nodes }, token| nodes.iter().map(|node| *node.borrow(token)).sum());
+///
+/// assert_eq!(43, sum);
+/// ```
+///
+/// Fields not projecting the brand, and only the brand, are rejected:
+///
+/// ```compile_fail,E0277
+/// use core::cell::Cell;
+///
+/// use ghost_sea::ghost_members;
+///
+/// ghost_members! {
+///     struct Shared<'brand> {
+///         cell: &'brand Cell<u32>,
+///     }
+///
+///     ref SharedRef;
+///     mut SharedMut;
+/// }
+/// ```
+///
+/// As are tokens, even hidden behind an alias:
+///
+/// ```compile_fail,E0277
+/// use ghost_sea::{ghost_members, GhostToken};
+///
+/// type Token<'b> = GhostToken<'b>;
+///
+/// ghost_members! {
+///     struct Smuggler<'brand> {
+///         token: Token<'brand>,
+///     }
+///
+///     ref SmugglerRef;
+///     mut SmugglerMut;
+/// }
+/// ```
+#[macro_export]
+macro_rules! ghost_members {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident<$brand:lifetime> {
+            $($(#[$field_attr:meta])* $field_vis:vis $field:ident: $field_ty:ty),* $(,)?
+        }
+
+        ref $ref_name:ident;
+        mut $mut_name:ident;
+    ) => {
+        $(#[$attr])*
+        $vis struct $name<$brand> {
+            $($(#[$field_attr])* $field_vis $field: $field_ty,)*
+        }
+
+        #[doc = concat!("Shared borrows of the members of `", stringify!($name), "`.")]
+        $vis struct $ref_name<'a, $brand> {
+            $(
+                #[doc = concat!("Borrow of `", stringify!($field), "`.")]
+                $field_vis $field: &'a $field_ty,
+            )*
+        }
+
+        #[doc = concat!("Exclusive borrows of the members of `", stringify!($name), "`.")]
+        $vis struct $mut_name<'a, $brand> {
+            $(
+                #[doc = concat!("Borrow of `", stringify!($field), "`.")]
+                $field_vis $field: &'a mut $field_ty,
+            )*
+        }
+
+        //  Safety:
+        //  -   `'static` is the brand, and only the brand, as verified below.
+        unsafe impl<'id> $crate::GhostProject<'id> for $name<'static> {
+            type Branded = $name<'id>;
+        }
+
+        impl<'id> $crate::GhostUnproject<'id> for $name<'id> {
+            type Unbranded = $name<'static>;
+        }
+
+        //  Safety:
+        //  -   No field contains a token, as verified below.
+        unsafe impl<'id> $crate::__private::ProjectField<'id> for $name<'static> {}
+
+        impl<'a, $brand> $crate::GhostMembers for &'a $name<$brand> {
+            type Members = $ref_name<'a, $brand>;
+
+            fn members(self) -> Self::Members { $ref_name { $($field: &self.$field,)* } }
+        }
+
+        impl<'a, $brand> $crate::GhostMembers for &'a mut $name<$brand> {
+            type Members = $mut_name<'a, $brand>;
+
+            fn members(self) -> Self::Members { $mut_name { $($field: &mut self.$field,)* } }
+        }
+
+        //  Every field is checked, through an alias rebranding it.
+        const _: () = {
+            $(
+                #[allow(non_camel_case_types)]
+                type $field<$brand> = $field_ty;
+            )*
+
+            #[allow(dead_code)]
+            fn __ghost_project_check<'id>() {
+                $($crate::__private::assert_project::<'id, $field<'static>, $field<'id>>();)*
+            }
+        };
+    };
+}
+
+/// Creates a `GhostFleet` from a list of members.
+///
+/// The invocation `ghost_fleet!(a, b, c)` is equivalent to `GhostFleet::new().with(a).with(b).with(c)`.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{ghost_fleet, GhostCell};
+///
+/// let fleet = ghost_fleet!(GhostCell::new(1u32), GhostCell::new(2u32));
+///
+/// assert_eq!(3, fleet.apply_ref(|(one, two), token| *one.borrow(token) + *two.borrow(token)));
+/// ```
+#[macro_export]
+macro_rules! ghost_fleet {
+    ($($member:expr),* $(,)?) => { $crate::GhostFleet::new()$(.with($member))* };
+}
+
+//  The generic parameters of the methods are named `Fun` and `R`, so as not to clash with those of the members.
+macro_rules! arity {
+    ($($name:ident),*; $last:ident) => {
+        impl<$($name,)*> GhostFleet<($($name,)*)>
+        where
+            $($name: for<'id> GhostProject<'id>,)*
+        {
+            /// Adds a member, returning the enlarged fleet.
+            #[allow(non_snake_case)]
+            #[inline(always)]
+            pub fn with<$last>(self, member: $last) -> GhostFleet<($($name,)* $last,)>
+            where
+                $last: for<'id> GhostProject<'id>,
+            {
+                let ($($name,)*) = self.0.into_inner();
+
+                GhostFleet(GhostSea::new(($($name,)* member,)))
+            }
+
+            /// Adds a member created by `fun`, returning the enlarged fleet.
+            ///
+            /// The member is created branded, hence may reference the cells of the existing members.
+            #[allow(non_snake_case)]
+            #[inline(always)]
+            pub fn with_branded<$last, Fun>(self, fun: Fun) -> GhostFleet<($($name,)* $last,)>
+            where
+                $last: for<'id> GhostProject<'id>,
+                for<'id> Fun: FnOnce(
+                    &mut ($(<$name as GhostProject<'id>>::Branded,)*),
+                    &mut GhostToken<'id>,
+                ) -> <$last as GhostProject<'id>>::Branded,
+            {
+                GhostFleet(self.0.map(|mut members, token| {
+                    let member = fun(&mut members, token);
+
+                    let ($($name,)*) = members;
+
+                    ($($name,)* member,)
+                }))
+            }
+        }
+
+        impl<$($name,)* $last> GhostFleet<($($name,)* $last,)>
+        where
+            $($name: for<'id> GhostProject<'id>,)*
+            $last: for<'id> GhostProject<'id>,
+        {
+            /// Removes the last member, returning the shrunk fleet, and the result of `fun`.
+            ///
+            /// See `without_at` to remove another member.
+            ///
+            /// As the cells of the removed member may be shared with the remaining members, it is handed branded to
+            /// `fun`, alongside the remaining members, for disposal.
+            #[allow(non_snake_case)]
+            #[inline(always)]
+            pub fn without<R, Fun>(self, fun: Fun) -> (GhostFleet<($($name,)*)>, R)
+            where
+                for<'id> Fun: FnOnce(
+                    &mut ($(<$name as GhostProject<'id>>::Branded,)*),
+                    <$last as GhostProject<'id>>::Branded,
+                    &mut GhostToken<'id>,
+                ) -> R,
+            {
+                let (sea, result) = self.0.map_split(|($($name,)* member,), token| {
+                    let mut members = ($($name,)*);
+
+                    let result = fun(&mut members, member, token);
+
+                    (members, result)
+                });
+
+                (GhostFleet(sea), result)
+            }
+        }
+    };
+}
+
+macro_rules! members {
+    ($($name:ident),*) => {
+        #[allow(non_snake_case, clippy::unused_unit)]
+        impl<'a, $($name,)*> GhostMembers for &'a ($($name,)*) {
+            type Members = ($(&'a $name,)*);
+
+            fn members(self) -> Self::Members {
+                let ($($name,)*) = self;
+
+                ($($name,)*)
+            }
+        }
+
+        #[allow(non_snake_case, clippy::unused_unit)]
+        impl<'a, $($name,)*> GhostMembers for &'a mut ($($name,)*) {
+            type Members = ($(&'a mut $name,)*);
+
+            fn members(self) -> Self::Members {
+                let ($($name,)*) = self;
+
+                ($($name,)*)
+            }
+        }
+    };
+}
+
+//  Implements `GhostRemove` for each position of a tuple, `$before` listing the members preceding the position.
+macro_rules! remove {
+    ([$($before:ident)*]) => {};
+    ([$($before:ident)*] $member:ident $($after:ident)*) => {
+        #[allow(non_snake_case)]
+        impl<$($before,)* $member, $($after,)*> GhostRemove<{ 0 $(+ remove!(@one $before))* }>
+            for ($($before,)* $member, $($after,)*)
+        where
+            $($before: for<'id> GhostProject<'id>,)*
+            $member: for<'id> GhostProject<'id>,
+            $($after: for<'id> GhostProject<'id>,)*
+        {
+            type Remaining = ($($before,)* $($after,)*);
+            type Removed = $member;
+
+            #[inline(always)]
+            fn remove<'id>(
+                ($($before,)* $member, $($after,)*): <Self as GhostProject<'id>>::Branded,
+            ) -> (<Self::Remaining as GhostProject<'id>>::Branded, <Self::Removed as GhostProject<'id>>::Branded) {
+                (($($before,)* $($after,)*), $member)
+            }
+        }
+
+        remove!([$($before)* $member] $($after)*);
+    };
+    (@one $name:ident) => { 1 };
+}
+
+remove!([] A);
+remove!([] A B);
+remove!([] A B C);
+remove!([] A B C D);
+remove!([] A B C D E);
+remove!([] A B C D E F);
+remove!([] A B C D E F G);
+remove!([] A B C D E F G H);
+remove!([] A B C D E F G H I);
+remove!([] A B C D E F G H I J);
+remove!([] A B C D E F G H I J K);
+remove!([] A B C D E F G H I J K L);
+
+members!();
+members!(A);
+members!(A, B);
+members!(A, B, C);
+members!(A, B, C, D);
+members!(A, B, C, D, E);
+members!(A, B, C, D, E, F);
+members!(A, B, C, D, E, F, G);
+members!(A, B, C, D, E, F, G, H);
+members!(A, B, C, D, E, F, G, H, I);
+members!(A, B, C, D, E, F, G, H, I, J);
+members!(A, B, C, D, E, F, G, H, I, J, K);
+members!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+arity!(; A);
+arity!(A; B);
+arity!(A, B; C);
+arity!(A, B, C; D);
+arity!(A, B, C, D; E);
+arity!(A, B, C, D, E; F);
+arity!(A, B, C, D, E, F; G);
+arity!(A, B, C, D, E, F, G; H);
+arity!(A, B, C, D, E, F, G, H; I);
+arity!(A, B, C, D, E, F, G, H, I; J);
+arity!(A, B, C, D, E, F, G, H, I, J; K);
+arity!(A, B, C, D, E, F, G, H, I, J, K; L);
@@ -0,0 +1,197 @@
+//  Formatting of branded data.
+//
+//  Branded data, such as the content of a `GhostCell`, can only be accessed with its `GhostToken`, and therefore cannot
+//  implement `core::fmt::Debug` or `core::fmt::Display` directly. Instead, it implements `GhostDebug` or
+//  `GhostDisplay`, which take the token as an extra argument, and is paired with its token in `WithToken` for use with
+//  the formatting machinery.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use ghost_cell::{GhostCell, GhostToken};
+
+/// Token-aware equivalent of `core::fmt::Debug`.
+///
+/// #   Examples
+///
+/// ```
+/// use core::fmt;
+///
+/// use ghost_sea::{GhostCell, GhostDebug, GhostProject, GhostSea, GhostToken, WithToken};
+///
+/// struct Names<'brand>(GhostCell<'brand, Vec<String>>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Names<'static> {
+///     type Branded = Names<'id>;
+/// }
+///
+/// impl<'brand> GhostDebug<'brand> for Names<'brand> {
+///     fn ghost_fmt(&self, token: &GhostToken<'brand>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.debug_tuple("Names").field(self.0.borrow(token)).finish()
+///     }
+/// }
+///
+/// let sea = GhostSea::new(Names(GhostCell::new(vec!["Alice".to_string()])));
+///
+/// assert_eq!(r#"GhostSea { value: Names(["Alice"]) }"#, format!("{:?}", sea));
+///
+/// let names = sea.apply_ref(|names, token| format!("{:?}", WithToken::new(names, token)));
+///
+/// assert_eq!(r#"Names(["Alice"])"#, names);
+/// ```
+pub trait GhostDebug<'brand> {
+    /// Formats the value using the given formatter, and token.
+    fn ghost_fmt(&self, token: &GhostToken<'brand>, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// Token-aware equivalent of `core::fmt::Display`.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostToken, WithToken};
+///
+/// GhostToken::new(|token| {
+///     let cell = GhostCell::new(42);
+///
+///     assert_eq!("42", format!("{}", WithToken::new(&cell, &token)));
+/// });
+/// ```
+pub trait GhostDisplay<'brand> {
+    /// Formats the value using the given formatter, and token.
+    fn ghost_fmt(&self, token: &GhostToken<'brand>, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// A value paired with its token.
+///
+/// Implements the standard formatting traits by delegating to their token-aware equivalents.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostToken, WithToken};
+///
+/// GhostToken::new(|token| {
+///     let pair = (GhostCell::new(1), GhostCell::new("One"));
+///
+///     assert_eq!(r#"(1, "One")"#, format!("{:?}", WithToken::new(&pair, &token)));
+/// });
+/// ```
+pub struct WithToken<'a, 'brand, T: ?Sized> {
+    value: &'a T,
+    token: &'a GhostToken<'brand>,
+}
+
+impl<'a, 'brand, T: ?Sized> WithToken<'a, 'brand, T> {
+    /// Creates a new instance.
+    #[inline(always)]
+    pub fn new(value: &'a T, token: &'a GhostToken<'brand>) -> Self { Self { value, token } }
+
+    /// Returns the value.
+    #[inline(always)]
+    pub fn value(&self) -> &'a T { self.value }
+
+    /// Returns the token.
+    #[inline(always)]
+    pub fn token(&self) -> &'a GhostToken<'brand> { self.token }
+}
+
+impl<'a, 'brand, T: ?Sized> Clone for WithToken<'a, 'brand, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, 'brand, T: ?Sized> Copy for WithToken<'a, 'brand, T> {}
+
+impl<'a, 'brand, T: ?Sized + GhostDebug<'brand>> fmt::Debug for WithToken<'a, 'brand, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.value.ghost_fmt(self.token, f) }
+}
+
+impl<'a, 'brand, T: ?Sized + GhostDisplay<'brand>> fmt::Display for WithToken<'a, 'brand, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.value.ghost_fmt(self.token, f) }
+}
+
+//
+//  Implementations
+//
+
+macro_rules! brand_free {
+    ($($t:ty),*) => {
+        $(
+            impl<'brand> GhostDebug<'brand> for $t {
+                fn ghost_fmt(&self, _: &GhostToken<'brand>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(self, f)
+                }
+            }
+
+            impl<'brand> GhostDisplay<'brand> for $t {
+                fn ghost_fmt(&self, _: &GhostToken<'brand>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+        )*
+    };
+}
+
+brand_free!(bool, char, f32, f64, i8, i16, i32, i64, i128, isize, str, u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "alloc")]
+brand_free!(String);
+
+impl<'brand> GhostDebug<'brand> for () {
+    fn ghost_fmt(&self, _: &GhostToken<'brand>, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(self, f) }
+}
+
+impl<'brand, T: ?Sized + GhostDebug<'brand>> GhostDebug<'brand> for &T {
+    fn ghost_fmt(&self, token: &GhostToken<'brand>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).ghost_fmt(token, f)
+    }
+}
+
+impl<'brand, T: ?Sized + GhostDisplay<'brand>> GhostDisplay<'brand> for &T {
+    fn ghost_fmt(&self, token: &GhostToken<'brand>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).ghost_fmt(token, f)
+    }
+}
+
+//  `GhostCell` is transparent: its content is formatted as if it were not wrapped.
+impl<'brand, T: GhostDebug<'brand>> GhostDebug<'brand> for GhostCell<'brand, T> {
+    fn ghost_fmt(&self, token: &GhostToken<'brand>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.borrow(token).ghost_fmt(token, f)
+    }
+}
+
+impl<'brand, T: GhostDisplay<'brand>> GhostDisplay<'brand> for GhostCell<'brand, T> {
+    fn ghost_fmt(&self, token: &GhostToken<'brand>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.borrow(token).ghost_fmt(token, f)
+    }
+}
+
+macro_rules! tuple {
+    ($($name:ident),+) => {
+        impl<'brand, $($name: GhostDebug<'brand>),+> GhostDebug<'brand> for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn ghost_fmt(&self, token: &GhostToken<'brand>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let ($($name,)+) = self;
+
+                f.debug_tuple("")
+                    $(.field(&WithToken::new($name, token)))+
+                    .finish()
+            }
+        }
+    };
+}
+
+tuple!(A);
+tuple!(A, B);
+tuple!(A, B, C);
+tuple!(A, B, C, D);
+tuple!(A, B, C, D, E);
+tuple!(A, B, C, D, E, F);
+tuple!(A, B, C, D, E, F, G);
+tuple!(A, B, C, D, E, F, G, H);
+tuple!(A, B, C, D, E, F, G, H, I);
+tuple!(A, B, C, D, E, F, G, H, I, J);
+tuple!(A, B, C, D, E, F, G, H, I, J, K);
+tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
@@ -1,21 +1,38 @@
 //  Sea comes the idea of "Sea of Nodes", which is a tenuous idea, for sure, but as Ghost Sea evokes pirates and
 //  adventures I'll cling to it!
 
-use core::mem::{self, MaybeUninit};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ptr,
+};
 
 use ghost_cell::GhostToken;
 
+#[cfg(feature = "alloc")]
+use crate::{GhostClone, GhostCloneContext};
+
+use crate::{
+    GhostDebug, GhostEq, GhostHash, GhostOrd, GhostOutput, GhostPartialEq, GhostPartialOrd, GhostSeaMut, GhostSeaRef,
+    WithToken,
+};
+
 /// Projects a non-branded type as a branded type.
 ///
 /// #   Safety
 ///
-/// The default implementation of the method is going to be a `mem::transmute`, the safety of which hinges on:
+/// The default implementation of the methods is going to be a pointer cast, the safety of which hinges on:
 ///
-/// -   The fact that `Self::Result` should be `Self` which just a few lifetimes switched to `'id`, and therefore have
-///     the same layout. Fingers crossed.
+/// -   The fact that `Self::Branded` should be `Self` which just a few lifetimes switched to `'id`, and therefore have
+///     the same layout. Size and alignment are checked at compile-time, the rest is fingers crossed.
 /// -   The implementer should _really_ take care that only the brands place-holders switch to `'id`.
 /// -   The implementer promises that the type does not already carry a `GhostToken`.
 ///
+/// With the `derive` feature, `#[derive(GhostProject)]` generates the implementation, and verifies the above.
+///
 /// #   Examples
 ///
 /// The typical example is to use on a `GhostCell`:
@@ -46,73 +63,237 @@ use ghost_cell::GhostToken;
 ///     type Branded = Node<'id>;
 /// }
 /// ```
+///
+/// Common compositions of projected types, such as tuples, arrays, `Option`, `Result`, or -- with the `alloc` feature
+/// -- `Box` or `Vec`, are projected structurally, and do not require an implementation of their own.
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostSea};
+///
+/// let mut sea = GhostSea::new(([GhostCell::new(1u32), GhostCell::new(2)], Some(GhostCell::new(3u32))));
+///
+/// sea.apply_mut(|(cells, total), token| {
+///     let sum: u32 = cells.iter().map(|cell| *cell.borrow(token)).sum();
+///
+///     *total.as_ref().unwrap().borrow_mut(token) += sum;
+/// });
+///
+/// assert_eq!(6, sea.apply_ref(|(_, total), token| *total.as_ref().unwrap().borrow(token)));
+/// ```
+///
+/// Shared pointers -- `Rc`, `Arc` and `StaticRc` -- are not projected. Their other owners may live in another
+/// `GhostSea`, or outside of any, hence projecting a `GhostCell` within would let two distinct tokens access it.
+/// Branded values are instead held by unique owners, such as `Box`, or by the collections, whose `StaticRc` never
+/// escape them:
+///
+/// ```compile_fail,E0599
+/// use std::rc::Rc;
+///
+/// use ghost_sea::{GhostCell, GhostSea};
+///
+/// let cell = Rc::new(GhostCell::new(1));
+///
+/// let (mut one, two) = (GhostSea::new(cell.clone()), GhostSea::new(cell));
+///
+/// one.apply_mut(|one, token| {
+///     let one = one.borrow_mut(token);
+///
+///     two.apply_ref(|two, token| assert_eq!(*one, *two.borrow(token)));
+/// });
+/// ```
+///
+/// A `Branded` type with a different size or alignment fails to compile as soon as any projection is used.
+///
+/// ```compile_fail,E0080
+/// use crate::ghost_sea::GhostProject;
+///
+/// struct Small(u8);
+///
+/// unsafe impl<'id> GhostProject<'id> for Small {
+///     type Branded = u64;
+/// }
+///
+/// let mut small = Small(1);
+/// small.project_mut();
+/// ```
 pub unsafe trait GhostProject<'id> : Sized {
     /// The result of the various projections.
-    ///
-    /// 
     type Branded;
 
     /// Projects `self` as a branded `Branded`.
     ///
-    /// #   Safety.
+    /// #   Safety
     ///
     /// Even assuming that `project` is correctly implemented, there are still multiple reasons for this to go wrong:
     ///
     /// -   Since `self` is potentially aliased, it should only be tied to an `'id` brand carried by a
     ///     `&GhostToken<'id>` to prevent mutation.
     #[inline(always)]
-    unsafe fn project(&self) -> &Self::Branded { mem::transmute(self) }
+    unsafe fn project(&self) -> &Self::Branded {
+        let () = SameLayout::<Self, Self::Branded>::CHECK;
+
+        &*(self as *const Self as *const Self::Branded)
+    }
 
     /// Projects `self` as a branded `Branded`.
     #[inline(always)]
     fn project_mut(&mut self) -> &mut Self::Branded {
+        let () = SameLayout::<Self, Self::Branded>::CHECK;
+
         //  Safety:
         //  -   `self` is borrowed mutably for the duration.
-        unsafe { mem::transmute(self) }
+        //  -   `Self` and `Self::Branded` have the same size and alignment.
+        unsafe { &mut *(self as *mut Self as *mut Self::Branded) }
     }
 
     /// Projects `self` as a branded `Branded`.
     #[inline(always)]
     fn project_once(self) -> Self::Branded {
-        assert_eq!(mem::size_of::<Self>(), mem::size_of::<Self::Branded>());
+        let () = SameLayout::<Self, Self::Branded>::CHECK;
 
-        let result = unsafe { mem::transmute_copy(&self) };
-        mem::forget(self);
+        let this = ManuallyDrop::new(self);
 
-        result
+        //  Safety:
+        //  -   `this` is never dropped, hence the value is moved out exactly once.
+        //  -   `Self` and `Self::Branded` have the same size and alignment.
+        unsafe { ptr::read(&*this as *const Self as *const Self::Branded) }
     }
 }
 
-unsafe impl<'a, 'id> GhostProject<'id> for GhostToken<'static> {
+unsafe impl<'id> GhostProject<'id> for GhostToken<'static> {
     type Branded = GhostToken<'id>;
 }
 
-/// Trait for use with `GhostSea::apply_ref`.
+/// Reverses the projection of a branded type, naming the non-branded type it is projected from.
+///
+/// The trait is used by `GhostSea::seal` to infer the type of the `GhostSea` from the type of the branded value. As
+/// `Unbranded` is required to project back onto `Self`, implementing it is safe.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostProject, GhostSea, GhostToken, GhostUnproject};
+///
+/// struct Names<'brand>(GhostCell<'brand, Vec<String>>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Names<'static> {
+///     type Branded = Names<'id>;
+/// }
+///
+/// impl<'id> GhostUnproject<'id> for Names<'id> {
+///     type Unbranded = Names<'static>;
+/// }
+///
+/// let sea = GhostToken::new(|mut token| {
+///     let names = Names(GhostCell::new(Vec::new()));
+///
+///     names.0.borrow_mut(&mut token).push("Alice".to_string());
+///
+///     GhostSea::seal(names, token)
+/// });
+///
+/// assert_eq!(1, sea.apply_ref(|names, token| names.0.borrow(token).len()));
+/// ```
+pub trait GhostUnproject<'id>: Sized {
+    /// The type projected onto `Self`.
+    type Unbranded: GhostProject<'id, Branded = Self>;
+}
+
+/// Trait for callbacks suitable for use with `GhostSea::apply_ref`.
+///
+/// The callback receives a borrow of lifetime `'a`, tied to the borrow of the `GhostSea`, of data and token branded
+/// with an arbitrary `'id`: the output may borrow for `'a`, but cannot name `'id`, and therefore cannot smuggle out
+/// either the token or branded data.
+///
+/// The trait is implemented by closures, and is meant for callbacks whose type needs to be named. An implementation
+/// `fun` is invoked as `sea.apply_ref(ghost_ref(fun))`.
+///
+/// #   Migration
+///
+/// The former `GhostApplyRef<'id, T>` unified the borrow of the `GhostSea` with the brand, which let the output
+/// mention the brand. Callbacks of the former signature, `for<'id> FnOnce(&'id ..., &'id GhostToken<'id>)`, are
+/// accepted unchanged by the deprecated `GhostSea::apply_ref_legacy` if their output does not borrow, and by
+/// `GhostSea::apply_ref_with` otherwise.
 ///
-/// Work-around for difficulties in describing the relationship between input-lifetime and output-lifetime of callbacks.
-pub trait GhostApplyRef<'id, T>
+/// Implementations of the former trait are rewritten against the current trait:
+///
+/// -   `impl<'id> GhostApplyRef<'id, T> for X` becomes `impl<'a> GhostApplyRef<'a, T> for X`.
+/// -   `fn call(self, ghost: &'id ..., token: &'id GhostToken<'id>)` becomes
+///     `fn call<'id>(self, ghost: &'a ..., token: &'a GhostToken<'id>)`.
+/// -   An `Output` borrowing from the data, formerly `&'id U`, becomes `&'a U`.
+/// -   `sea.apply_ref(x)` becomes `sea.apply_ref(ghost_ref(x))`.
+///
+/// The `Ret*Ref` forwarders implement the current trait, for the common shapes of output, and are likewise passed via
+/// `ghost_ref`, or created and adapted at once by `ghost_fn!`.
+pub trait GhostApplyRef<'a, T>
 where
     T: for<'x> GhostProject<'x>,
 {
-    /// Lifetime-parameterized output of the callback.
+    /// Output of the callback.
     type Output;
 
     /// Fowarder.
-    fn call(self, ghost: &'id <T as GhostProject<'id>>::Branded, token: &'id GhostToken<'id>) -> Self::Output;
+    fn call<'id>(self, ghost: &'a <T as GhostProject<'id>>::Branded, token: &'a GhostToken<'id>) -> Self::Output;
 }
 
-/// Trait for use with `GhostSea::apply_mut`.
+impl<'a, T, R, F> GhostApplyRef<'a, T> for F
+where
+    T: for<'x> GhostProject<'x>,
+    F: for<'id> FnOnce(&'a <T as GhostProject<'id>>::Branded, &'a GhostToken<'id>) -> R,
+{
+    type Output = R;
+
+    fn call<'id>(self, ghost: &'a <T as GhostProject<'id>>::Branded, token: &'a GhostToken<'id>) -> R {
+        self(ghost, token)
+    }
+}
+
+/// Trait for callbacks suitable for use with `GhostSea::apply_mut`.
+///
+/// The callback receives a borrow of lifetime `'a`, tied to the borrow of the `GhostSea`, of data and token branded
+/// with an arbitrary `'id`: the output may borrow for `'a`, but cannot name `'id`, and therefore cannot smuggle out
+/// either the token or branded data.
+///
+/// The trait is implemented by closures, and is meant for callbacks whose type needs to be named. An implementation
+/// `fun` is invoked as `sea.apply_mut(ghost_mut(fun))`.
+///
+/// #   Migration
 ///
-/// Work-around for difficulties in describing the relationship between input-lifetime and output-lifetime of callbacks.
-pub trait GhostApplyMut<'id, T>
+/// As for `GhostApplyRef`, callbacks of the former signature, `for<'id> FnOnce(&'id mut ..., &'id mut GhostToken<'id>)`,
+/// are accepted unchanged by the deprecated `GhostSea::apply_mut_legacy` if their output does not borrow, and by
+/// `GhostSea::apply_mut_with` otherwise.
+///
+/// Implementations of the former `GhostApplyMut<'id, T>` are rewritten against the current trait:
+///
+/// -   `impl<'id> GhostApplyMut<'id, T> for X` becomes `impl<'a> GhostApplyMut<'a, T> for X`.
+/// -   `fn call(self, ghost: &'id mut ..., token: &'id mut GhostToken<'id>)` becomes
+///     `fn call<'id>(self, ghost: &'a mut ..., token: &'a mut GhostToken<'id>)`.
+/// -   An `Output` borrowing from the data, formerly `&'id mut U`, becomes `&'a mut U`.
+/// -   `sea.apply_mut(x)` becomes `sea.apply_mut(ghost_mut(x))`.
+///
+/// The `Ret*Mut` forwarders implement the current trait, and are likewise passed via `ghost_mut`, or `ghost_fn!`.
+pub trait GhostApplyMut<'a, T>
 where
     T: for<'x> GhostProject<'x>,
 {
-    /// Lifetime-parameterized output of the callback.
+    /// Output of the callback.
     type Output;
 
     /// Fowarder.
-    fn call(self, ghost: &'id mut <T as GhostProject<'id>>::Branded, token: &'id mut GhostToken<'id>) -> Self::Output;
+    fn call<'id>(self, ghost: &'a mut <T as GhostProject<'id>>::Branded, token: &'a mut GhostToken<'id>)
+        -> Self::Output;
+}
+
+impl<'a, T, R, F> GhostApplyMut<'a, T> for F
+where
+    T: for<'x> GhostProject<'x>,
+    F: for<'id> FnOnce(&'a mut <T as GhostProject<'id>>::Branded, &'a mut GhostToken<'id>) -> R,
+{
+    type Output = R;
+
+    fn call<'id>(self, ghost: &'a mut <T as GhostProject<'id>>::Branded, token: &'a mut GhostToken<'id>) -> R {
+        self(ghost, token)
+    }
 }
 
 /// Ergonomic wrapper around the usage of `GhostCell` and `GhostToken`.
@@ -150,10 +331,80 @@ impl<T> GhostSea<T> {
         Self { token, value, }
     }
 
+    /// Creates a new instance from a branded value, and its token.
+    ///
+    /// This is the reverse of `apply_once`: the token is consumed, hence no other value bearing its brand can ever be
+    /// accessed again, and the value may be paired with a fresh token.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{GhostCell, GhostSea};
+    ///
+    /// let sea = GhostSea::new(GhostCell::new(1));
+    ///
+    /// let sea = sea.apply_once(|cell, mut token| {
+    ///     *cell.borrow_mut(&mut token) += 1;
+    ///
+    ///     GhostSea::seal(cell, token)
+    /// });
+    ///
+    /// assert_eq!(2, sea.apply_ref(|cell, token| *cell.borrow(token)));
+    /// ```
+    ///
+    /// The token cannot be used once sealed:
+    ///
+    /// ```compile_fail,E0382
+    /// use ghost_sea::{GhostCell, GhostSea, GhostToken};
+    ///
+    /// GhostToken::new(|token| {
+    ///     let cell = GhostCell::new(1);
+    ///     let other = GhostCell::new(2);
+    ///
+    ///     let sea = GhostSea::seal(cell, token);
+    ///
+    ///     assert_eq!(2, *other.borrow(&token));
+    /// });
+    /// ```
+    #[inline(always)]
+    pub fn seal<'id, B>(value: B, _token: GhostToken<'id>) -> Self
+    where
+        B: GhostUnproject<'id, Unbranded = T>,
+        T: GhostProject<'id, Branded = B>,
+    {
+        //  Safety:
+        //  -   The token is consumed, hence the brand cannot remain in use, should any other branded value linger.
+        //  -   `value` is moved, hence cannot share any branded data with another value but through pointers, whose
+        //      dereference requires `unsafe`.
+        Self::new(unsafe { unproject::<T>(value) })
+    }
+
     /// Returns the value contained within.
     #[inline(always)]
     pub fn into_inner(self) -> T { self.value }
 
+    //  Returns the value and token contained within, un-projected.
+    //
+    //  #   Safety
+    //
+    //  -   All `GhostSea` share the `'static` brand, hence the caller should ensure that neither the token nor any
+    //      branded data escape, lest they be mixed with those of another `GhostSea`.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub(crate) unsafe fn parts(&self) -> (&T, &GhostToken<'static>) { (&self.value, &self.token) }
+
+    //  Returns the value and token contained within, un-projected.
+    //
+    //  #   Safety
+    //
+    //  -   All `GhostSea` share the `'static` brand, hence the caller should ensure that neither the token nor any
+    //      branded data escape, lest they be mixed with those of another `GhostSea`.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub(crate) unsafe fn parts_mut(&mut self) -> (&mut T, &mut GhostToken<'static>) {
+        (&mut self.value, &mut self.token)
+    }
+
     //  Returns a generated token.
     //
     //  #   Safety
@@ -173,29 +424,247 @@ where
     T: for<'id> GhostProject<'id>,
 {
     /// Apply the provided function, and return its result.
+    ///
+    /// The function is called with a borrow of `self`, branded with an arbitrary `'id`. The result may borrow from
+    /// `self`, as long as it does not mention `'id`.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{GhostCell, GhostProject, GhostSea};
+    ///
+    /// struct Names<'brand>(GhostCell<'brand, Vec<String>>);
+    ///
+    /// unsafe impl<'id> GhostProject<'id> for Names<'static> {
+    ///     type Branded = Names<'id>;
+    /// }
+    ///
+    /// let mut sea = GhostSea::new(Names(GhostCell::new(vec!["Alice".to_string()])));
+    ///
+    /// sea.apply_mut(|names, token| names.0.borrow_mut(token).push("Bob".to_string()));
+    ///
+    /// let last: Option<&str> = sea.apply_ref(|names, token| names.0.borrow(token).last().map(|s| s.as_str()));
+    ///
+    /// assert_eq!(Some("Bob"), last);
+    /// ```
+    ///
+    /// The token, or any branded data, cannot escape:
+    ///
+    /// ```compile_fail
+    /// use ghost_sea::{GhostCell, GhostProject, GhostSea};
+    ///
+    /// struct Name<'brand>(GhostCell<'brand, String>);
+    ///
+    /// unsafe impl<'id> GhostProject<'id> for Name<'static> {
+    ///     type Branded = Name<'id>;
+    /// }
+    ///
+    /// let sea = GhostSea::new(Name(GhostCell::new("Alice".to_string())));
+    ///
+    /// let token = sea.apply_ref(|_, token| token);
+    /// ```
     #[inline(always)]
-    pub fn apply_ref<'a, G>(&'a self, fun: G) -> <G as GhostApplyRef<'a, T>>::Output
+    pub fn apply_ref<'a, R, F>(&'a self, fun: F) -> R
     where
-        G: for<'id> GhostApplyRef<'id, T>,
+        F: for<'id> FnOnce(&'a <T as GhostProject<'id>>::Branded, &'a GhostToken<'id>) -> R,
     {
         //  Safety:
         //  -   Pair &T with &GhostToken, so read-only.
-        let token: &'a GhostToken<'a> = unsafe { self.token.project() };
-        let value: &'a <T as GhostProject<'a>>::Branded = unsafe { self.value.project() };
+        let token = unsafe { self.token.project() };
+        let value = unsafe { self.value.project() };
 
-        fun.call(value, token)
+        fun(value, token)
     }
 
     /// Apply the provided function, and return its result.
+    ///
+    /// The function is called with a borrow of `self`, branded with an arbitrary `'id`. The result may borrow from
+    /// `self`, as long as it does not mention `'id`.
+    #[inline(always)]
+    pub fn apply_mut<'a, R, F>(&'a mut self, fun: F) -> R
+    where
+        F: for<'id> FnOnce(&'a mut <T as GhostProject<'id>>::Branded, &'a mut GhostToken<'id>) -> R,
+    {
+        let token = self.token.project_mut();
+        let value = self.value.project_mut();
+
+        fun(value, token)
+    }
+
+    /// Apply the provided function, whose borrow is unified with the brand, and return its result.
+    ///
+    /// Unlike `apply_ref`, the function is called with `&'id` data and token branded with `'id`, and returns the
+    /// family `O` instantiated for `'id`, as `fn f<'id>(ghost: &'id ..., token: &'id GhostToken<'id>) -> O::Of<'id>`
+    /// does; the result is tied to the borrow of `self`. See `GhostOutput`.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{GhostCell, GhostProject, GhostSea, GhostToken};
+    ///
+    /// struct Names<'brand>(GhostCell<'brand, Vec<String>>);
+    ///
+    /// unsafe impl<'id> GhostProject<'id> for Names<'static> {
+    ///     type Branded = Names<'id>;
+    /// }
+    ///
+    /// fn first<'id>(names: &'id Names<'id>, token: &'id GhostToken<'id>) -> Option<&'id String> {
+    ///     names.0.borrow(token).first()
+    /// }
+    ///
+    /// let sea = GhostSea::new(Names(GhostCell::new(vec!["Alice".to_string()])));
+    ///
+    /// assert_eq!("Alice", sea.apply_ref_with::<Option<&String>, _>(first).unwrap());
+    /// ```
+    ///
+    /// Such a function does not fit `apply_ref`, whose borrow is independent of the brand:
+    ///
+    /// ```compile_fail
+    /// use ghost_sea::{GhostCell, GhostProject, GhostSea, GhostToken};
+    ///
+    /// struct Names<'brand>(GhostCell<'brand, Vec<String>>);
+    ///
+    /// unsafe impl<'id> GhostProject<'id> for Names<'static> {
+    ///     type Branded = Names<'id>;
+    /// }
+    ///
+    /// fn first<'id>(names: &'id Names<'id>, token: &'id GhostToken<'id>) -> Option<&'id String> {
+    ///     names.0.borrow(token).first()
+    /// }
+    ///
+    /// let sea = GhostSea::new(Names(GhostCell::new(vec!["Alice".to_string()])));
+    ///
+    /// assert_eq!("Alice", sea.apply_ref(first).unwrap());
+    /// ```
+    #[inline(always)]
+    pub fn apply_ref_with<'a, O, F>(&'a self, fun: F) -> O::Of<'a>
+    where
+        O: GhostOutput + 'a,
+        F: for<'id> FnOnce(&'id <T as GhostProject<'id>>::Branded, &'id GhostToken<'id>) -> O::Of<'id>,
+    {
+        //  Safety:
+        //  -   Pair &T with &GhostToken, so read-only.
+        //  -   The brand is `'a`, yet the output cannot carry the token nor branded data, as `O::Of<'a>` only uses `'a`
+        //      as the lifetime of borrows, as per `GhostOutput`.
+        let token = unsafe { <GhostToken<'static> as GhostProject<'a>>::project(&self.token) };
+        let value = unsafe { <T as GhostProject<'a>>::project(&self.value) };
+
+        fun(value, token)
+    }
+
+    /// Apply the provided function, whose borrow is unified with the brand, and return its result.
+    ///
+    /// Unlike `apply_mut`, the function is called with `&'id mut` data and token branded with `'id`, and returns the
+    /// family `O` instantiated for `'id`; the result is tied to the borrow of `self`. See `apply_ref_with`.
+    #[inline(always)]
+    pub fn apply_mut_with<'a, O, F>(&'a mut self, fun: F) -> O::Of<'a>
+    where
+        O: GhostOutput + 'a,
+        F: for<'id> FnOnce(&'id mut <T as GhostProject<'id>>::Branded, &'id mut GhostToken<'id>) -> O::Of<'id>,
+    {
+        //  The brand is `'a`, yet the output cannot carry the token nor branded data, as `O::Of<'a>` only uses `'a` as
+        //  the lifetime of borrows, as per `GhostOutput`.
+        let token = <GhostToken<'static> as GhostProject<'a>>::project_mut(&mut self.token);
+        let value = <T as GhostProject<'a>>::project_mut(&mut self.value);
+
+        fun(value, token)
+    }
+
+    /// Apply the provided function, of the signature expected by the former `apply_ref`, and return its result.
+    ///
+    /// The function is called as by `apply_ref_with`, and its result cannot borrow from `self`.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// use ghost_sea::{GhostCell, GhostProject, GhostSea, GhostToken};
+    ///
+    /// struct Names<'brand>(GhostCell<'brand, Vec<String>>);
+    ///
+    /// unsafe impl<'id> GhostProject<'id> for Names<'static> {
+    ///     type Branded = Names<'id>;
+    /// }
+    ///
+    /// fn count<'id>(names: &'id Names<'id>, token: &'id GhostToken<'id>) -> usize { names.0.borrow(token).len() }
+    ///
+    /// let mut sea = GhostSea::new(Names(GhostCell::new(vec!["Alice".to_string()])));
+    ///
+    /// sea.apply_mut_legacy(|names, token| names.0.borrow_mut(token).push("Bob".to_string()));
+    ///
+    /// assert_eq!(2, sea.apply_ref_legacy(count));
+    /// ```
+    #[deprecated(note = "use `apply_ref`, or `apply_ref_with` for callbacks unifying the borrow with the brand")]
+    #[inline(always)]
+    pub fn apply_ref_legacy<'a, R, F>(&'a self, fun: F) -> R
+    where
+        F: for<'id> FnOnce(&'id <T as GhostProject<'id>>::Branded, &'id GhostToken<'id>) -> R,
+    {
+        //  Safety:
+        //  -   Pair &T with &GhostToken, so read-only.
+        //  -   The brand is `'a`, yet `R` is chosen independently of the brand of the callback, hence cannot carry the
+        //      token nor branded data.
+        let token = unsafe { <GhostToken<'static> as GhostProject<'a>>::project(&self.token) };
+        let value = unsafe { <T as GhostProject<'a>>::project(&self.value) };
+
+        fun(value, token)
+    }
+
+    /// Apply the provided function, of the signature expected by the former `apply_mut`, and return its result.
+    ///
+    /// The function is called as by `apply_mut_with`, and its result cannot borrow from `self`. See `apply_ref_legacy`.
+    #[deprecated(note = "use `apply_mut`, or `apply_mut_with` for callbacks unifying the borrow with the brand")]
+    #[inline(always)]
+    pub fn apply_mut_legacy<'a, R, F>(&'a mut self, fun: F) -> R
+    where
+        F: for<'id> FnOnce(&'id mut <T as GhostProject<'id>>::Branded, &'id mut GhostToken<'id>) -> R,
+    {
+        //  The brand is `'a`, yet `R` is chosen independently of the brand of the callback, hence cannot carry the token
+        //  nor branded data.
+        let token = <GhostToken<'static> as GhostProject<'a>>::project_mut(&mut self.token);
+        let value = <T as GhostProject<'a>>::project_mut(&mut self.value);
+
+        fun(value, token)
+    }
+
+    /// Apply the provided function, and return a guard over the brand-free reference it returns.
+    ///
+    /// See `GhostSeaRef` for the operations available on the guard.
     #[inline(always)]
-    pub fn apply_mut<'a, G>(&'a mut self, fun: G) -> <G as GhostApplyMut<'a, T>>::Output
+    pub fn map_ref<'a, U: ?Sized, F>(&'a self, fun: F) -> GhostSeaRef<'a, T, U>
     where
-        G: for<'id> GhostApplyMut<'id, T>,
+        F: for<'id> FnOnce(&'a <T as GhostProject<'id>>::Branded, &'a GhostToken<'id>) -> &'a U,
     {
-        let token: &'a mut GhostToken<'a> = self.token.project_mut();
-        let value: &'a mut <T as GhostProject<'a>>::Branded = self.value.project_mut();
+        GhostSeaRef::new(self.apply_ref(fun))
+    }
 
-        fun.call(value, token)
+    /// Apply the provided function, and return a guard over the brand-free reference it returns, if any.
+    #[inline(always)]
+    pub fn filter_map_ref<'a, U: ?Sized, F>(&'a self, fun: F) -> Option<GhostSeaRef<'a, T, U>>
+    where
+        F: for<'id> FnOnce(&'a <T as GhostProject<'id>>::Branded, &'a GhostToken<'id>) -> Option<&'a U>,
+    {
+        self.apply_ref(fun).map(GhostSeaRef::new)
+    }
+
+    /// Apply the provided function, and return a guard over the brand-free reference it returns.
+    ///
+    /// See `GhostSeaMut` for the operations available on the guard.
+    #[inline(always)]
+    pub fn map_mut<'a, U: ?Sized, F>(&'a mut self, fun: F) -> GhostSeaMut<'a, T, U>
+    where
+        F: for<'id> FnOnce(&'a mut <T as GhostProject<'id>>::Branded, &'a mut GhostToken<'id>) -> &'a mut U,
+    {
+        GhostSeaMut::new(self.apply_mut(fun))
+    }
+
+    /// Apply the provided function, and return a guard over the brand-free reference it returns, if any.
+    #[inline(always)]
+    pub fn filter_map_mut<'a, U: ?Sized, F>(&'a mut self, fun: F) -> Option<GhostSeaMut<'a, T, U>>
+    where
+        F: for<'id> FnOnce(&'a mut <T as GhostProject<'id>>::Branded, &'a mut GhostToken<'id>) -> Option<&'a mut U>,
+    {
+        self.apply_mut(fun).map(GhostSeaMut::new)
     }
 
     /// Apply the provided function, and return its result.
@@ -210,12 +679,295 @@ where
         fun(value, token)
     }
 
+    /// Apply the provided function, and return a new instance over its result.
+    ///
+    /// The function consumes the branded value, and transforms it into another branded value, with the same brand. As
+    /// the token is only borrowed, the result is paired with it, and no branded data is ever exposed as `'static`.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{GhostCell, GhostSea};
+    ///
+    /// let sea = GhostSea::new([GhostCell::new(1u32), GhostCell::new(2), GhostCell::new(3)]);
+    ///
+    /// //  Keeps only the cells holding an even number.
+    /// let sea: GhostSea<[Option<GhostCell<u32>>; 3]> = sea.map(|cells, token| {
+    ///     cells.map(|cell| if *cell.borrow(token) % 2 == 0 { Some(cell) } else { None })
+    /// });
+    ///
+    /// assert_eq!(1, sea.apply_ref(|cells, _| cells.iter().flatten().count()));
+    /// ```
+    #[inline(always)]
+    pub fn map<U, F>(self, fun: F) -> GhostSea<U>
+    where
+        for<'id> U: GhostProject<'id>,
+        for<'id> F: FnOnce(<T as GhostProject<'id>>::Branded, &mut GhostToken<'id>)
+            -> <U as GhostProject<'id>>::Branded,
+    {
+        let mut token = self.token.project_once();
+        let value = self.value.project_once();
+
+        let value = fun(value, &mut token);
+
+        //  Safety:
+        //  -   `value` is all that remains of the branded data, and is paired with its token.
+        GhostSea { token, value: unsafe { unproject::<U>(value) } }
+    }
+
+    /// Apply the provided function, and return a new instance over the first element of its result, alongside the
+    /// second.
+    ///
+    /// See `map`; the second element cannot mention the brand, and is returned as is.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{GhostCell, GhostSea};
+    ///
+    /// let sea = GhostSea::new([GhostCell::new(1u32), GhostCell::new(2), GhostCell::new(3)]);
+    ///
+    /// //  Removes the last cell, returning its value.
+    /// let (sea, last): (GhostSea<[GhostCell<u32>; 2]>, _) =
+    ///     sea.map_split(|[one, two, three], _| ([one, two], three.into_inner()));
+    ///
+    /// assert_eq!(3, last);
+    /// assert_eq!(3, sea.apply_ref(|cells, token| cells.iter().map(|cell| *cell.borrow(token)).sum::<u32>()));
+    /// ```
+    #[inline(always)]
+    pub fn map_split<U, R, F>(self, fun: F) -> (GhostSea<U>, R)
+    where
+        for<'id> U: GhostProject<'id>,
+        for<'id> F: FnOnce(<T as GhostProject<'id>>::Branded, &mut GhostToken<'id>)
+            -> (<U as GhostProject<'id>>::Branded, R),
+    {
+        let mut token = self.token.project_once();
+        let value = self.value.project_once();
+
+        let (value, result) = fun(value, &mut token);
+
+        //  Safety:
+        //  -   `value` is all that remains of the branded data, and is paired with its token.
+        (GhostSea { token, value: unsafe { unproject::<U>(value) } }, result)
+    }
+
+    /// Apply the provided function, and return a new instance over its result, if successful.
+    ///
+    /// See `map`; on error, the value is dropped and the error is returned.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{GhostCell, GhostSea};
+    ///
+    /// type Cells<T> = [GhostCell<'static, T>; 2];
+    ///
+    /// //  Parses each digit, or returns the first character which is not one.
+    /// fn parse(sea: GhostSea<Cells<char>>) -> Result<GhostSea<Cells<u32>>, char> {
+    ///     sea.try_map(|[one, two], _| {
+    ///         let (one, two) = (one.into_inner(), two.into_inner());
+    ///
+    ///         Ok([GhostCell::new(one.to_digit(10).ok_or(one)?), GhostCell::new(two.to_digit(10).ok_or(two)?)])
+    ///     })
+    /// }
+    ///
+    /// let sea = parse(GhostSea::new([GhostCell::new('1'), GhostCell::new('2')])).unwrap();
+    ///
+    /// assert_eq!(3, sea.apply_ref(|cells, token| cells.iter().map(|cell| *cell.borrow(token)).sum::<u32>()));
+    ///
+    /// let sea = GhostSea::new([GhostCell::new('1'), GhostCell::new('x')]);
+    ///
+    /// assert_eq!(Err('x'), parse(sea).map(|_| ()));
+    /// ```
+    ///
+    /// The value is dropped exactly once on error:
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    ///
+    /// use ghost_sea::{GhostProject, GhostSea};
+    ///
+    /// struct Tracked<'a>(&'a Cell<u32>);
+    ///
+    /// unsafe impl<'id, 'a> GhostProject<'id> for Tracked<'a> {
+    ///     type Branded = Tracked<'a>;
+    /// }
+    ///
+    /// impl Drop for Tracked<'_> {
+    ///     fn drop(&mut self) { self.0.set(self.0.get() + 1); }
+    /// }
+    ///
+    /// let drops = Cell::new(0);
+    ///
+    /// let result: Result<GhostSea<()>, &str> = GhostSea::new(Tracked(&drops)).try_map(|_, _| Err("failed"));
+    ///
+    /// assert_eq!(Err("failed"), result.map(|_| ()));
+    /// assert_eq!(1, drops.get());
+    /// ```
+    #[inline(always)]
+    pub fn try_map<U, E, F>(self, fun: F) -> Result<GhostSea<U>, E>
+    where
+        for<'id> U: GhostProject<'id>,
+        for<'id> F: FnOnce(<T as GhostProject<'id>>::Branded, &mut GhostToken<'id>)
+            -> Result<<U as GhostProject<'id>>::Branded, E>,
+    {
+        let mut token = self.token.project_once();
+        let value = self.value.project_once();
+
+        let value = fun(value, &mut token)?;
+
+        //  Safety:
+        //  -   `value` is all that remains of the branded data, and is paired with its token.
+        Ok(GhostSea { token, value: unsafe { unproject::<U>(value) } })
+    }
+
+    /// Apply the provided function, and return its result.
+    ///
+    /// The function is called with borrows of `self` and `other`, each branded with its own arbitrary brand, and each
+    /// paired with its own token. Unlike `combine_ref`, `other` is only borrowed.
+    ///
+    /// See `ghost_zip!` to borrow more than two instances at once.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{GhostCell, GhostSea};
+    ///
+    /// let weights = GhostSea::new([GhostCell::new(1u32), GhostCell::new(2)]);
+    /// let values = GhostSea::new([GhostCell::new(10u32), GhostCell::new(20)]);
+    ///
+    /// let total: u32 = weights.zip_ref(&values, |weights, weights_token, values, values_token| {
+    ///     weights.iter().zip(values).map(|(w, v)| *w.borrow(weights_token) * *v.borrow(values_token)).sum()
+    /// });
+    ///
+    /// assert_eq!(50, total);
+    /// ```
+    ///
+    /// The cells of one instance cannot be accessed with the token of the other:
+    ///
+    /// ```compile_fail
+    /// use ghost_sea::{GhostCell, GhostSea};
+    ///
+    /// let one = GhostSea::new(GhostCell::new(1u32));
+    /// let two = GhostSea::new(GhostCell::new(2u32));
+    ///
+    /// one.zip_ref(&two, |one, _, _, two_token| *one.borrow(two_token));
+    /// ```
+    #[inline(always)]
+    pub fn zip_ref<'a, R, O, F>(&'a self, other: &'a GhostSea<O>, fun: F) -> R
+    where
+        for<'id> O: GhostProject<'id>,
+        F: for<'a1, 'a2> FnOnce(
+            &'a <T as GhostProject<'a1>>::Branded,
+            &'a GhostToken<'a1>,
+            &'a <O as GhostProject<'a2>>::Branded,
+            &'a GhostToken<'a2>,
+        ) -> R,
+    {
+        self.apply_ref(move |value, token| {
+            GhostSea::apply_ref(other, move |other, other_token| fun(value, token, other, other_token))
+        })
+    }
+
+    /// Apply the provided function, and return its result.
+    ///
+    /// See `zip_ref`; both `self` and `other` are borrowed mutably.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use core::mem;
+    ///
+    /// use ghost_sea::{GhostCell, GhostSea};
+    ///
+    /// let mut source = GhostSea::new([GhostCell::new(1u32), GhostCell::new(2)]);
+    /// let mut target = GhostSea::new([GhostCell::new(0u32), GhostCell::new(0)]);
+    ///
+    /// //  Moves the values of `source` to `target`, scaling them along the way.
+    /// source.zip_mut(&mut target, |source, source_token, target, target_token| {
+    ///     for (from, to) in source.iter().zip(target.iter()) {
+    ///         *to.borrow_mut(target_token) = mem::take(from.borrow_mut(source_token)) * 10;
+    ///     }
+    /// });
+    ///
+    /// assert_eq!((0, 0), source.apply_ref(|[one, two], token| (*one.borrow(token), *two.borrow(token))));
+    /// assert_eq!((10, 20), target.apply_ref(|[one, two], token| (*one.borrow(token), *two.borrow(token))));
+    /// ```
+    #[inline(always)]
+    pub fn zip_mut<'a, R, O, F>(&'a mut self, other: &'a mut GhostSea<O>, fun: F) -> R
+    where
+        for<'id> O: GhostProject<'id>,
+        F: for<'a1, 'a2> FnOnce(
+            &'a mut <T as GhostProject<'a1>>::Branded,
+            &'a mut GhostToken<'a1>,
+            &'a mut <O as GhostProject<'a2>>::Branded,
+            &'a mut GhostToken<'a2>,
+        ) -> R,
+    {
+        self.apply_mut(move |value, token| {
+            GhostSea::apply_mut(other, move |other, other_token| fun(value, token, other, other_token))
+        })
+    }
+
+    /// Apply the provided function, and return its result.
+    ///
+    /// See `zip_ref`; `self` is borrowed immutably, and `other` mutably.
+    ///
+    /// #   Examples
+    ///
+    /// ```
+    /// use ghost_sea::{GhostCell, GhostSea};
+    ///
+    /// let index = GhostSea::new([GhostCell::new(1u32), GhostCell::new(2)]);
+    /// let mut cache = GhostSea::new(GhostCell::new(0u32));
+    ///
+    /// index.zip_ref_mut(&mut cache, |index, index_token, cache, cache_token| {
+    ///     *cache.borrow_mut(cache_token) = index.iter().map(|cell| *cell.borrow(index_token)).sum();
+    /// });
+    ///
+    /// assert_eq!(3, cache.apply_ref(|cache, token| *cache.borrow(token)));
+    /// ```
+    #[inline(always)]
+    pub fn zip_ref_mut<'a, R, O, F>(&'a self, other: &'a mut GhostSea<O>, fun: F) -> R
+    where
+        for<'id> O: GhostProject<'id>,
+        F: for<'a1, 'a2> FnOnce(
+            &'a <T as GhostProject<'a1>>::Branded,
+            &'a GhostToken<'a1>,
+            &'a mut <O as GhostProject<'a2>>::Branded,
+            &'a mut GhostToken<'a2>,
+        ) -> R,
+    {
+        self.apply_ref(move |value, token| {
+            GhostSea::apply_mut(other, move |other, other_token| fun(value, token, other, other_token))
+        })
+    }
+
     /// Apply the provided function, and return its result.
+    ///
+    /// See `zip_ref`; `self` is borrowed mutably, and `other` immutably.
     #[inline(always)]
-    pub fn combine_ref<R, O, F>(&self, other: GhostSea<O>, fun: F) -> R
+    pub fn zip_mut_ref<'a, R, O, F>(&'a mut self, other: &'a GhostSea<O>, fun: F) -> R
     where
         for<'id> O: GhostProject<'id>,
-        for<'id> F: FnOnce(&'id <T as GhostProject<'id>>::Branded, <O as GhostProject<'id>>::Branded, &'id GhostToken<'id>) -> R,
+        F: for<'a1, 'a2> FnOnce(
+            &'a mut <T as GhostProject<'a1>>::Branded,
+            &'a mut GhostToken<'a1>,
+            &'a <O as GhostProject<'a2>>::Branded,
+            &'a GhostToken<'a2>,
+        ) -> R,
+    {
+        self.apply_mut(move |value, token| {
+            GhostSea::apply_ref(other, move |other, other_token| fun(value, token, other, other_token))
+        })
+    }
+
+    /// Apply the provided function, and return its result.
+    #[inline(always)]
+    pub fn combine_ref<'a, R, O, F>(&'a self, other: GhostSea<O>, fun: F) -> R
+    where
+        for<'id> O: GhostProject<'id>,
+        for<'id> F: FnOnce(&'a <T as GhostProject<'id>>::Branded, <O as GhostProject<'id>>::Branded, &'a GhostToken<'id>) -> R,
     {
         //  Safety:
         //  -   Pair &T with &GhostToken, so read-only.
@@ -229,10 +981,10 @@ where
 
     /// Apply the provided function, and return its result.
     #[inline(always)]
-    pub fn combine_mut<R, O, F>(&mut self, other: GhostSea<O>, fun: F) -> R
+    pub fn combine_mut<'a, R, O, F>(&'a mut self, other: GhostSea<O>, fun: F) -> R
     where
         for<'id> O: GhostProject<'id>,
-        for<'id> F: FnOnce(&'id mut <T as GhostProject<'id>>::Branded, <O as GhostProject<'id>>::Branded, &'id mut GhostToken<'id>) -> R,
+        for<'id> F: FnOnce(&'a mut <T as GhostProject<'id>>::Branded, <O as GhostProject<'id>>::Branded, &'a mut GhostToken<'id>) -> R,
     {
         let token = self.token.project_mut();
         let value = self.value.project_mut();
@@ -262,6 +1014,132 @@ impl<T: Default> Default for GhostSea<T> {
     fn default() -> Self { Self::new(T::default()) }
 }
 
+//  Safety:
+//  -   The clone shares no branded data with `self`, as per `GhostClone`, hence may be paired with a fresh token.
+#[cfg(feature = "alloc")]
+impl<T> Clone for GhostSea<T>
+where
+    T: for<'id> GhostProject<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostClone<'id>,
+{
+    fn clone(&self) -> Self {
+        let value = self.apply_ref(|value, token| unsafe {
+            unproject::<T>(value.ghost_clone(&mut GhostCloneContext::new(), token))
+        });
+
+        Self::new(value)
+    }
+}
+
+impl<T> fmt::Debug for GhostSea<T>
+where
+    T: for<'id> GhostProject<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostDebug<'id>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.apply_ref(|value, token| f.debug_struct("GhostSea").field("value", &WithToken::new(value, token)).finish())
+    }
+}
+
+//  Comparisons project both values with the same brand, and use the token of `self` to access either.
+impl<T> PartialEq for GhostSea<T>
+where
+    T: for<'id> GhostProject<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostPartialEq<'id>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.apply_ref(|value, token| {
+            //  Safety:
+            //  -   `other` is borrowed immutably, hence no `&mut` to its token exists for the duration, and none of its
+            //      cells may be borrowed mutably: reading them with the token of `self` is read-only.
+            //  -   `self` is borrowed immutably too, and its token is shared, hence its cells are read-only as well.
+            let other = unsafe { other.value.project() };
+
+            value.ghost_eq(other, token)
+        })
+    }
+}
+
+impl<T> Eq for GhostSea<T>
+where
+    T: for<'id> GhostProject<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostEq<'id>,
+{
+}
+
+impl<T> PartialOrd for GhostSea<T>
+where
+    T: for<'id> GhostProject<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostPartialOrd<'id>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.apply_ref(|value, token| {
+            //  Safety:
+            //  -   `other` is borrowed immutably, hence no `&mut` to its token exists for the duration, and none of its
+            //      cells may be borrowed mutably: reading them with the token of `self` is read-only.
+            //  -   `self` is borrowed immutably too, and its token is shared, hence its cells are read-only as well.
+            let other = unsafe { other.value.project() };
+
+            value.ghost_partial_cmp(other, token)
+        })
+    }
+}
+
+impl<T> Ord for GhostSea<T>
+where
+    T: for<'id> GhostProject<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostOrd<'id>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.apply_ref(|value, token| {
+            //  Safety:
+            //  -   `other` is borrowed immutably, hence no `&mut` to its token exists for the duration, and none of its
+            //      cells may be borrowed mutably: reading them with the token of `self` is read-only.
+            //  -   `self` is borrowed immutably too, and its token is shared, hence its cells are read-only as well.
+            let other = unsafe { other.value.project() };
+
+            value.ghost_cmp(other, token)
+        })
+    }
+}
+
+impl<T> Hash for GhostSea<T>
+where
+    T: for<'id> GhostProject<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostHash<'id>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) { self.apply_ref(|value, token| value.ghost_hash(state, token)) }
+}
+
+//
+//  Implementation
+//
+
+//  Reverses the projection of `value`.
+//
+//  #   Safety
+//
+//  -   `value` shall not share any branded data with another value, as it may be paired with another token.
+unsafe fn unproject<'id, T: GhostProject<'id>>(value: T::Branded) -> T {
+    let () = SameLayout::<T, T::Branded>::CHECK;
+
+    let value = ManuallyDrop::new(value);
+
+    ptr::read(&*value as *const T::Branded as *const T)
+}
+
+//  Post-monomorphization check that `S` and `B` share the same size and alignment.
+//
+//  Referring to `CHECK` forces its evaluation for the specific `S` and `B`, failing the build if they differ.
+struct SameLayout<S, B>(PhantomData<(S, B)>);
+
+impl<S, B> SameLayout<S, B> {
+    const CHECK: () = assert!(
+        mem::size_of::<S>() == mem::size_of::<B>() && mem::align_of::<S>() == mem::align_of::<B>(),
+        "GhostProject::Branded should have the same layout as Self"
+    );
+}
+
 /*
 error: internal compiler error: compiler/rustc_trait_selection/src/traits/codegen.rs:78:17:
 
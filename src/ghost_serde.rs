@@ -0,0 +1,211 @@
+//  Serialization of branded data.
+//
+//  Serializing branded data requires access to its `GhostToken`, hence `GhostSerialize`, a token-aware equivalent of
+//  `serde::Serialize`. Deserialization, on the other hand, creates fresh data which no token can access yet, hence
+//  `serde::Deserialize` suffices.
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use ghost_cell::{GhostCell, GhostToken};
+use serde::{ser::SerializeTuple, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{GhostDrop, GhostDropSea, GhostProject, GhostSea, WithToken};
+
+/// Token-aware equivalent of `serde::Serialize`.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostProject, GhostSea, GhostSerialize, GhostToken};
+/// use serde::{Deserialize, Deserializer, Serializer};
+///
+/// struct Scores<'brand>(GhostCell<'brand, [u32; 2]>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Scores<'static> {
+///     type Branded = Scores<'id>;
+/// }
+///
+/// impl<'brand> GhostSerialize<'brand> for Scores<'brand> {
+///     fn ghost_serialize<S: Serializer>(&self, serializer: S, token: &GhostToken<'brand>) -> Result<S::Ok, S::Error> {
+///         self.0.ghost_serialize(serializer, token)
+///     }
+/// }
+///
+/// impl<'de, 'brand> Deserialize<'de> for Scores<'brand> {
+///     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+///         <[u32; 2]>::deserialize(deserializer).map(|scores| Scores(GhostCell::new(scores)))
+///     }
+/// }
+///
+/// let sea = GhostSea::new(Scores(GhostCell::new([3, 5])));
+///
+/// let json = serde_json::to_string(&sea).unwrap();
+///
+/// assert_eq!("[3,5]", json);
+///
+/// let sea: GhostSea<Scores<'static>> = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(8, sea.apply_ref(|scores, token| scores.0.borrow(token).iter().sum::<u32>()));
+/// ```
+pub trait GhostSerialize<'brand> {
+    /// Serializes `self` with the given serializer, and token.
+    fn ghost_serialize<S: Serializer>(&self, serializer: S, token: &GhostToken<'brand>) -> Result<S::Ok, S::Error>;
+}
+
+impl<'a, 'brand, T: ?Sized + GhostSerialize<'brand>> Serialize for WithToken<'a, 'brand, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value().ghost_serialize(serializer, self.token())
+    }
+}
+
+//  The value is serialized transparently.
+impl<T> Serialize for GhostSea<T>
+where
+    T: for<'id> GhostProject<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostSerialize<'id>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.apply_ref(|value, token| value.ghost_serialize(serializer, token))
+    }
+}
+
+//  The value is deserialized transparently, and paired with a fresh token.
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for GhostSea<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(GhostSea::new)
+    }
+}
+
+impl<T> Serialize for GhostDropSea<T>
+where
+    T: for<'id> GhostDrop<'id>,
+    for<'id> <T as GhostProject<'id>>::Branded: GhostSerialize<'id>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.apply_ref(|value, token| value.ghost_serialize(serializer, token))
+    }
+}
+
+impl<'de, T> Deserialize<'de> for GhostDropSea<T>
+where
+    T: for<'id> GhostDrop<'id> + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(GhostDropSea::new)
+    }
+}
+
+//
+//  Implementations
+//
+
+macro_rules! brand_free {
+    ($($t:ty),*) => {
+        $(
+            impl<'brand> GhostSerialize<'brand> for $t {
+                #[inline(always)]
+                fn ghost_serialize<S>(&self, serializer: S, _: &GhostToken<'brand>) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    self.serialize(serializer)
+                }
+            }
+        )*
+    };
+}
+
+brand_free!((), bool, char, f32, f64, i8, i16, i32, i64, i128, isize, str, u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "alloc")]
+impl<'brand> GhostSerialize<'brand> for String {
+    fn ghost_serialize<S: Serializer>(&self, serializer: S, _: &GhostToken<'brand>) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+impl<'brand, T: ?Sized + GhostSerialize<'brand>> GhostSerialize<'brand> for &T {
+    fn ghost_serialize<S: Serializer>(&self, serializer: S, token: &GhostToken<'brand>) -> Result<S::Ok, S::Error> {
+        (**self).ghost_serialize(serializer, token)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'brand, T: ?Sized + GhostSerialize<'brand>> GhostSerialize<'brand> for Box<T> {
+    fn ghost_serialize<S: Serializer>(&self, serializer: S, token: &GhostToken<'brand>) -> Result<S::Ok, S::Error> {
+        (**self).ghost_serialize(serializer, token)
+    }
+}
+
+impl<'brand, T: GhostSerialize<'brand>> GhostSerialize<'brand> for Option<T> {
+    fn ghost_serialize<S: Serializer>(&self, serializer: S, token: &GhostToken<'brand>) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&WithToken::new(value, token)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'brand, T: GhostSerialize<'brand>> GhostSerialize<'brand> for [T] {
+    fn ghost_serialize<S: Serializer>(&self, serializer: S, token: &GhostToken<'brand>) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|value| WithToken::new(value, token)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'brand, T: GhostSerialize<'brand>> GhostSerialize<'brand> for Vec<T> {
+    fn ghost_serialize<S: Serializer>(&self, serializer: S, token: &GhostToken<'brand>) -> Result<S::Ok, S::Error> {
+        self.as_slice().ghost_serialize(serializer, token)
+    }
+}
+
+//  Arrays are serialized as tuples, as per `serde`.
+impl<'brand, T: GhostSerialize<'brand>, const N: usize> GhostSerialize<'brand> for [T; N] {
+    fn ghost_serialize<S: Serializer>(&self, serializer: S, token: &GhostToken<'brand>) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+
+        for value in self {
+            tuple.serialize_element(&WithToken::new(value, token))?;
+        }
+
+        tuple.end()
+    }
+}
+
+//  `GhostCell` is transparent: its content is serialized as if it were not wrapped.
+impl<'brand, T: GhostSerialize<'brand>> GhostSerialize<'brand> for GhostCell<'brand, T> {
+    fn ghost_serialize<S: Serializer>(&self, serializer: S, token: &GhostToken<'brand>) -> Result<S::Ok, S::Error> {
+        self.borrow(token).ghost_serialize(serializer, token)
+    }
+}
+
+macro_rules! tuple {
+    ($len:literal; $($name:ident $index:tt),+) => {
+        impl<'brand, $($name: GhostSerialize<'brand>),+> GhostSerialize<'brand> for ($($name,)+) {
+            fn ghost_serialize<S>(&self, serializer: S, token: &GhostToken<'brand>) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut tuple = serializer.serialize_tuple($len)?;
+
+                $(tuple.serialize_element(&WithToken::new(&self.$index, token))?;)+
+
+                tuple.end()
+            }
+        }
+    };
+}
+
+tuple!(1; A 0);
+tuple!(2; A 0, B 1);
+tuple!(3; A 0, B 1, C 2);
+tuple!(4; A 0, B 1, C 2, D 3);
+tuple!(5; A 0, B 1, C 2, D 3, E 4);
+tuple!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+tuple!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+tuple!(9; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+tuple!(10; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+tuple!(11; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+tuple!(12; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
@@ -0,0 +1,199 @@
+//  Guards over a borrow of a `GhostSea`, giving access to a brand-free part of its content.
+//
+//  Once projected, a brand-free reference no longer needs the token, and may therefore outlive the callback; the guard
+//  merely keeps the `GhostSea` borrowed for as long as the reference lives, much like `core::cell::Ref`.
+
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use crate::GhostSea;
+
+/// A shared borrow of a brand-free `U` within a `GhostSea<T>`.
+///
+/// Created by `GhostSea::map_ref`, or `GhostSea::filter_map_ref`.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostProject, GhostSea, GhostSeaRef};
+///
+/// struct Names<'brand>(GhostCell<'brand, Vec<String>>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Names<'static> {
+///     type Branded = Names<'id>;
+/// }
+///
+/// let sea = GhostSea::new(Names(GhostCell::new(vec!["Alice".to_string(), "Bob".to_string()])));
+///
+/// let names = sea.map_ref(|names, token| names.0.borrow(token).as_slice());
+/// assert_eq!(2, names.len());
+///
+/// let bob = GhostSeaRef::filter_map(names, |names| names.get(1)).ok().unwrap();
+/// let bob = GhostSeaRef::map(bob, |bob| bob.as_str());
+///
+/// assert_eq!("Bob", &*bob);
+/// ```
+///
+/// The `GhostSea` remains borrowed for as long as the guard lives:
+///
+/// ```compile_fail,E0502
+/// use ghost_sea::{GhostCell, GhostProject, GhostSea};
+///
+/// struct Names<'brand>(GhostCell<'brand, Vec<String>>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Names<'static> {
+///     type Branded = Names<'id>;
+/// }
+///
+/// let mut sea = GhostSea::new(Names(GhostCell::new(vec!["Alice".to_string()])));
+///
+/// let names = sea.map_ref(|names, token| names.0.borrow(token).as_slice());
+///
+/// sea.map_mut(|names, token| names.0.borrow_mut(token)).clear();
+///
+/// assert_eq!(1, names.len());
+/// ```
+pub struct GhostSeaRef<'a, T, U: ?Sized> {
+    value: &'a U,
+    _sea: PhantomData<&'a GhostSea<T>>,
+}
+
+impl<'a, T, U: ?Sized> GhostSeaRef<'a, T, U> {
+    /// Maps the guard to a part of the borrowed data.
+    ///
+    /// This is an associated function, to avoid conflicts with methods of `U`.
+    #[inline(always)]
+    pub fn map<V: ?Sized, F>(this: Self, fun: F) -> GhostSeaRef<'a, T, V>
+    where
+        F: FnOnce(&'a U) -> &'a V,
+    {
+        GhostSeaRef::new(fun(this.value))
+    }
+
+    /// Maps the guard to an optional part of the borrowed data, returning the original guard if `None`.
+    ///
+    /// This is an associated function, to avoid conflicts with methods of `U`.
+    #[inline(always)]
+    pub fn filter_map<V: ?Sized, F>(this: Self, fun: F) -> Result<GhostSeaRef<'a, T, V>, Self>
+    where
+        F: FnOnce(&'a U) -> Option<&'a V>,
+    {
+        match fun(this.value) {
+            Some(value) => Ok(GhostSeaRef::new(value)),
+            None => Err(this),
+        }
+    }
+
+    //  Creates an instance.
+    #[inline(always)]
+    pub(crate) fn new(value: &'a U) -> Self { Self { value, _sea: PhantomData } }
+}
+
+impl<'a, T, U: ?Sized> Clone for GhostSeaRef<'a, T, U> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T, U: ?Sized> Copy for GhostSeaRef<'a, T, U> {}
+
+impl<'a, T, U: ?Sized> Deref for GhostSeaRef<'a, T, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U { self.value }
+}
+
+impl<'a, T, U: ?Sized + fmt::Debug> fmt::Debug for GhostSeaRef<'a, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.value.fmt(f) }
+}
+
+impl<'a, T, U: ?Sized + fmt::Display> fmt::Display for GhostSeaRef<'a, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.value.fmt(f) }
+}
+
+/// An exclusive borrow of a brand-free `U` within a `GhostSea<T>`.
+///
+/// Created by `GhostSea::map_mut`, or `GhostSea::filter_map_mut`.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostProject, GhostSea, GhostSeaMut};
+///
+/// struct Names<'brand>(GhostCell<'brand, Vec<String>>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Names<'static> {
+///     type Branded = Names<'id>;
+/// }
+///
+/// let mut sea = GhostSea::new(Names(GhostCell::new(vec!["Alice".to_string()])));
+///
+/// let mut names = sea.map_mut(|names, token| names.0.borrow_mut(token));
+/// names.push("Bob".to_string());
+///
+/// let mut bob = GhostSeaMut::filter_map(names, |names| names.last_mut()).ok().unwrap();
+/// bob.make_ascii_uppercase();
+///
+/// assert_eq!("BOB", &*bob);
+/// ```
+pub struct GhostSeaMut<'a, T, U: ?Sized> {
+    value: &'a mut U,
+    _sea: PhantomData<&'a mut GhostSea<T>>,
+}
+
+impl<'a, T, U: ?Sized> GhostSeaMut<'a, T, U> {
+    /// Maps the guard to a part of the borrowed data.
+    ///
+    /// This is an associated function, to avoid conflicts with methods of `U`.
+    #[inline(always)]
+    pub fn map<V: ?Sized, F>(this: Self, fun: F) -> GhostSeaMut<'a, T, V>
+    where
+        F: FnOnce(&'a mut U) -> &'a mut V,
+    {
+        GhostSeaMut::new(fun(this.value))
+    }
+
+    /// Maps the guard to an optional part of the borrowed data, returning the original guard if `None`.
+    ///
+    /// This is an associated function, to avoid conflicts with methods of `U`.
+    #[inline(always)]
+    pub fn filter_map<V: ?Sized, F>(this: Self, fun: F) -> Result<GhostSeaMut<'a, T, V>, Self>
+    where
+        F: FnOnce(&mut U) -> Option<&mut V>,
+    {
+        let value: *mut U = this.value;
+
+        //  Safety:
+        //  -   `value` is derived from `this.value`, which is exclusively borrowed for `'a`, and `this` is consumed.
+        //  -   On failure, the borrow handed to `fun` has ended, hence `value` is once again exclusive.
+        //
+        //  This is the same work-around as used by `core::cell::RefMut::filter_map`, pending Polonius.
+        match fun(unsafe { &mut *value }) {
+            Some(value) => Ok(GhostSeaMut::new(value)),
+            None => Err(GhostSeaMut::new(unsafe { &mut *value })),
+        }
+    }
+
+    //  Creates an instance.
+    #[inline(always)]
+    pub(crate) fn new(value: &'a mut U) -> Self { Self { value, _sea: PhantomData } }
+}
+
+impl<'a, T, U: ?Sized> Deref for GhostSeaMut<'a, T, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U { self.value }
+}
+
+impl<'a, T, U: ?Sized> DerefMut for GhostSeaMut<'a, T, U> {
+    fn deref_mut(&mut self) -> &mut U { self.value }
+}
+
+impl<'a, T, U: ?Sized + fmt::Debug> fmt::Debug for GhostSeaMut<'a, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.value.fmt(f) }
+}
+
+impl<'a, T, U: ?Sized + fmt::Display> fmt::Display for GhostSeaMut<'a, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.value.fmt(f) }
+}
@@ -3,6 +3,17 @@
 //! -   An implementation of `GhostCell` and `GhostToken`, as per http://plv.mpi-sws.org/rustbelt/ghostcell/.
 //! -   A new `GhostSea` type, attempting to make the use of the above more ergonomic.
 //!
+//! Additionally, a number of collections built upon `GhostSea` are provided in the `collections` module.
+//!
+//! #   Features
+//!
+//! The crate is `no_std`, and offers the following features:
+//!
+//! -   `alloc` (default): enables the `collections` module, and `GhostClone`, which require the `alloc` crate.
+//! -   `derive`: enables `#[derive(GhostProject)]`, which verifies that only the brand is projected.
+//! -   `serde`: enables `Serialize` and `Deserialize` for `GhostSea` and the collections, through `GhostSerialize`.
+//! -   `std`: implies `alloc`, and enables conversions between the collections and their `std` counterparts.
+//!
 //! #   Safety
 //!
 //! http://plv.mpi-sws.org/rustbelt/ghostcell/ left some blanks in the implementation of `GhostCell` and `GhostToken`
@@ -17,11 +28,98 @@
 //! Use at your own risk!
 
 //  Generic features.
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 
 //  Lints.
 #![deny(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod collections;
+
+mod forwarder;
+#[cfg(feature = "alloc")]
+mod ghost_clone;
+mod ghost_cmp;
+mod ghost_drop;
+mod ghost_fleet;
+mod ghost_fmt;
 mod ghost_sea;
+#[cfg(feature = "serde")]
+mod ghost_serde;
+mod guard;
+mod output;
+mod project;
+mod zip;
+
+pub use ghost_cell::{GhostCell, GhostToken};
 
+#[cfg(feature = "derive")]
+pub use ghost_sea_derive::GhostProject;
+
+pub use self::forwarder::*;
+#[cfg(feature = "alloc")]
+pub use self::ghost_clone::*;
+pub use self::ghost_cmp::*;
+pub use self::ghost_drop::*;
+pub use self::ghost_fleet::*;
+pub use self::ghost_fmt::*;
 pub use self::ghost_sea::*;
+#[cfg(feature = "serde")]
+pub use self::ghost_serde::*;
+pub use self::guard::*;
+pub use self::output::*;
+
+//  Implementation details of `#[derive(GhostProject)]`, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use crate::{GhostProject, GhostToken};
+
+    /// Marker of the types which project the brand, and only the brand, and contain no `GhostToken`.
+    ///
+    /// `GhostToken<'static>` implements `GhostProject`, hence a token hidden behind an alias taking the brand as a
+    /// parameter would pass `GhostProject` checks; it does not implement `ProjectField`.
+    ///
+    /// #   Safety
+    ///
+    /// The type must not contain a `GhostToken` of the brand.
+    pub unsafe trait ProjectField<'id> {}
+
+    //  Asserts that `S` projects onto itself, and is therefore free of brand and token.
+    #[inline(always)]
+    pub fn assert_brand_free<'id, S>()
+    where
+        S: GhostProject<'id, Branded = S>,
+    {
+    }
+
+    //  Asserts that `S` projects onto `B`, and contains no token.
+    #[inline(always)]
+    pub fn assert_project<'id, S, B>()
+    where
+        S: GhostProject<'id, Branded = B> + ProjectField<'id>,
+    {
+    }
+
+    //  Checks that `F` is suitable for `GhostSea<S>::apply_ref`, guiding the inference of closures.
+    #[inline(always)]
+    pub fn ref_fn<'a, S, R, F>(fun: F) -> F
+    where
+        S: for<'id> GhostProject<'id>,
+        F: for<'id> FnOnce(&'a <S as GhostProject<'id>>::Branded, &'a GhostToken<'id>) -> R,
+    {
+        fun
+    }
+
+    //  Checks that `F` is suitable for `GhostSea<S>::apply_mut`, guiding the inference of closures.
+    #[inline(always)]
+    pub fn mut_fn<'a, S, R, F>(fun: F) -> F
+    where
+        S: for<'id> GhostProject<'id>,
+        F: for<'id> FnOnce(&'a mut <S as GhostProject<'id>>::Branded, &'a mut GhostToken<'id>) -> R,
+    {
+        fun
+    }
+}
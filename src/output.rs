@@ -0,0 +1,92 @@
+//  Families of output types, for use with `GhostSea::apply_ref_with` and `GhostSea::apply_mut_with`.
+//
+//  A family describes the shape of the output of a callback, independently of the lifetime of the borrow, which is
+//  only known at the point of call.
+
+/// A family of output types, parameterized by the lifetime of the borrow of the `GhostSea`.
+///
+/// Any lifetime appearing in the family itself is irrelevant, hence `Option<&T>` is the family of `Option<&'a T>`.
+///
+/// The callbacks of `GhostSea::apply_ref_with` and `GhostSea::apply_mut_with` unify the borrow with the brand, as
+/// `fn f<'id>(ghost: &'id ..., token: &'id GhostToken<'id>) -> O::Of<'id>` does, which a closure passed to
+/// `GhostSea::apply_ref` cannot express; the family names the output for any such `'id`.
+///
+/// Ready-made implementations are provided for:
+///
+/// -   `&U` and `&mut U`, including slices and `str`.
+/// -   `Option<O>` and `Result<O, E>`, where `O` is itself a family.
+/// -   Tuples of up to 4 families.
+/// -   Primitive types, which are their own family.
+///
+/// #   Safety
+///
+/// `Of<'a>` must only use `'a` as the lifetime of borrows, never as a brand: `GhostToken<'a>`, or branded data, would
+/// otherwise escape the callback.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{GhostCell, GhostProject, GhostSea};
+///
+/// struct Scores<'brand>(GhostCell<'brand, [u32; 3]>);
+///
+/// unsafe impl<'id> GhostProject<'id> for Scores<'static> {
+///     type Branded = Scores<'id>;
+/// }
+///
+/// let sea = GhostSea::new(Scores(GhostCell::new([3, 1, 2])));
+///
+/// let (scores, best) = sea.apply_ref_with::<(&[u32], Option<&u32>), _>(|scores, token| {
+///     let scores = scores.0.borrow(token);
+///
+///     (&scores[..], scores.iter().max())
+/// });
+///
+/// assert_eq!(&[3, 1, 2], scores);
+/// assert_eq!(Some(&3), best);
+/// ```
+pub unsafe trait GhostOutput {
+    /// The output, for a borrow of lifetime `'a`.
+    type Of<'a> where Self: 'a;
+}
+
+unsafe impl<U: ?Sized> GhostOutput for &U {
+    type Of<'a> = &'a U where Self: 'a;
+}
+
+unsafe impl<U: ?Sized> GhostOutput for &mut U {
+    type Of<'a> = &'a mut U where Self: 'a;
+}
+
+unsafe impl<O: GhostOutput> GhostOutput for Option<O> {
+    type Of<'a> = Option<O::Of<'a>> where Self: 'a;
+}
+
+unsafe impl<O: GhostOutput, E> GhostOutput for Result<O, E> {
+    type Of<'a> = Result<O::Of<'a>, E> where Self: 'a;
+}
+
+macro_rules! tuple {
+    ($($name:ident),*) => {
+        unsafe impl<$($name: GhostOutput),*> GhostOutput for ($($name,)*) {
+            type Of<'a> = ($($name::Of<'a>,)*) where Self: 'a;
+        }
+    };
+}
+
+tuple!(A);
+tuple!(A, B);
+tuple!(A, B, C);
+tuple!(A, B, C, D);
+
+macro_rules! value {
+    ($($t:ty),*) => {
+        $(
+            unsafe impl GhostOutput for $t {
+                type Of<'a> = $t;
+            }
+        )*
+    };
+}
+
+value!((), bool, char, f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
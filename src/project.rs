@@ -0,0 +1,177 @@
+//  Implementations of `GhostProject`, and its reverse `GhostUnproject`, for types defined outside this crate.
+//
+//  Three families of implementations are provided:
+//
+//  -   Brand-free types, such as `u32` or `String`, which project onto themselves.
+//  -   Branded types, such as `GhostCell`, which project structurally: only the brand place-holders switch to `'id`.
+//  -   Containers, such as `Option` or `Vec`, which project structurally as well: only their elements are projected.
+//
+//  Shared pointers, such as `Rc`, are not projected: should a `GhostCell<'static, T>` be shared between two `GhostSea`,
+//  it could be borrowed mutably through one, and immutably through the other, at the same time.
+//
+//  Each implementation of `GhostProject` is mirrored by an implementation of `GhostUnproject`, mapping the branded type
+//  back onto the non-branded one, and by an implementation of `ProjectField`, allowing the type as a field of the
+//  types checked by `#[derive(GhostProject)]` and `ghost_members!`.
+
+use core::{marker::PhantomData, ptr::NonNull};
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::VecDeque, string::String, vec::Vec};
+
+use ghost_cell::GhostCell;
+
+use crate::{__private::ProjectField, GhostProject, GhostUnproject};
+
+macro_rules! brand_free {
+    ($($t:ty),*) => {
+        $(
+            //  Safety:
+            //  -   There is no brand, hence nothing to switch.
+            unsafe impl<'id> GhostProject<'id> for $t {
+                type Branded = $t;
+            }
+
+            impl<'id> GhostUnproject<'id> for $t {
+                type Unbranded = $t;
+            }
+
+            //  Safety:
+            //  -   There is no token.
+            unsafe impl<'id> ProjectField<'id> for $t {}
+        )*
+    };
+}
+
+brand_free!((), bool, char, f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "alloc")]
+brand_free!(String);
+
+//  Safety:
+//  -   `'static` is the brand, and only the brand, and `T` is projected accordingly.
+unsafe impl<'id, T> GhostProject<'id> for GhostCell<'static, T>
+where
+    T: GhostProject<'id>,
+{
+    type Branded = GhostCell<'id, T::Branded>;
+}
+
+impl<'id, T> GhostUnproject<'id> for GhostCell<'id, T>
+where
+    T: GhostUnproject<'id>,
+{
+    type Unbranded = GhostCell<'static, T::Unbranded>;
+}
+
+//  Safety:
+//  -   `T` contains no token.
+unsafe impl<'id, T> ProjectField<'id> for GhostCell<'static, T> where T: ProjectField<'id> {}
+
+macro_rules! container {
+    ($([$($g:tt)*] $t:ty => $b:ty),*) => {
+        $(
+            //  Safety:
+            //  -   Only the elements are projected, as per their own implementation.
+            unsafe impl<'id, $($g)*> GhostProject<'id> for $t {
+                type Branded = $b;
+            }
+        )*
+    };
+}
+
+macro_rules! field_container {
+    ($([$($g:tt)*] $t:ty),*) => {
+        $(
+            //  Safety:
+            //  -   The elements contain no token.
+            unsafe impl<'id, $($g)*> ProjectField<'id> for $t {}
+        )*
+    };
+}
+
+macro_rules! unproject_container {
+    ($([$($g:tt)*] $b:ty => $t:ty),*) => {
+        $(
+            impl<'id, $($g)*> GhostUnproject<'id> for $b {
+                type Unbranded = $t;
+            }
+        )*
+    };
+}
+
+container!(
+    [T: GhostProject<'id>, const N: usize] [T; N] => [T::Branded; N],
+    [T: GhostProject<'id>] Option<T> => Option<T::Branded>,
+    [T: GhostProject<'id>, E: GhostProject<'id>] Result<T, E> => Result<T::Branded, E::Branded>,
+    [T: GhostProject<'id>] PhantomData<T> => PhantomData<T::Branded>,
+    //  Dereferencing a `NonNull` is unsafe, hence the user is responsible for not sharing branded values.
+    [T: GhostProject<'id>] NonNull<T> => NonNull<T::Branded>
+);
+
+#[cfg(feature = "alloc")]
+container!(
+    [T: GhostProject<'id>] Box<T> => Box<T::Branded>,
+    [T: GhostProject<'id>] Vec<T> => Vec<T::Branded>,
+    [T: GhostProject<'id>] VecDeque<T> => VecDeque<T::Branded>
+);
+
+field_container!(
+    [T: ProjectField<'id>, const N: usize] [T; N],
+    [T: ProjectField<'id>] Option<T>,
+    [T: ProjectField<'id>, E: ProjectField<'id>] Result<T, E>,
+    [T: ProjectField<'id>] PhantomData<T>,
+    [T: ProjectField<'id>] NonNull<T>
+);
+
+#[cfg(feature = "alloc")]
+field_container!(
+    [T: ProjectField<'id>] Box<T>,
+    [T: ProjectField<'id>] Vec<T>,
+    [T: ProjectField<'id>] VecDeque<T>
+);
+
+unproject_container!(
+    [T: GhostUnproject<'id>, const N: usize] [T; N] => [T::Unbranded; N],
+    [T: GhostUnproject<'id>] Option<T> => Option<T::Unbranded>,
+    [T: GhostUnproject<'id>, E: GhostUnproject<'id>] Result<T, E> => Result<T::Unbranded, E::Unbranded>,
+    [T: GhostUnproject<'id>] PhantomData<T> => PhantomData<T::Unbranded>,
+    [T: GhostUnproject<'id>] NonNull<T> => NonNull<T::Unbranded>
+);
+
+#[cfg(feature = "alloc")]
+unproject_container!(
+    [T: GhostUnproject<'id>] Box<T> => Box<T::Unbranded>,
+    [T: GhostUnproject<'id>] Vec<T> => Vec<T::Unbranded>,
+    [T: GhostUnproject<'id>] VecDeque<T> => VecDeque<T::Unbranded>
+);
+
+macro_rules! tuple {
+    ($($name:ident),+) => {
+        //  Safety:
+        //  -   Only the elements are projected, as per their own implementation.
+        unsafe impl<'id, $($name: GhostProject<'id>),+> GhostProject<'id> for ($($name,)+) {
+            type Branded = ($($name::Branded,)+);
+        }
+
+        impl<'id, $($name: GhostUnproject<'id>),+> GhostUnproject<'id> for ($($name,)+) {
+            type Unbranded = ($($name::Unbranded,)+);
+        }
+
+        //  Safety:
+        //  -   The elements contain no token.
+        unsafe impl<'id, $($name: ProjectField<'id>),+> ProjectField<'id> for ($($name,)+) {}
+    };
+}
+
+tuple!(A);
+tuple!(A, B);
+tuple!(A, B, C);
+tuple!(A, B, C, D);
+tuple!(A, B, C, D, E);
+tuple!(A, B, C, D, E, F);
+tuple!(A, B, C, D, E, F, G);
+tuple!(A, B, C, D, E, F, G, H);
+tuple!(A, B, C, D, E, F, G, H, I);
+tuple!(A, B, C, D, E, F, G, H, I, J);
+tuple!(A, B, C, D, E, F, G, H, I, J, K);
+tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
@@ -0,0 +1,60 @@
+//  Borrows of several independent `GhostSea` at once.
+//
+//  Each `GhostSea` is applied in turn, nesting the calls, hence each is branded with its own arbitrary brand, and the
+//  cells of one cannot be accessed with the token of another.
+
+/// Borrows several `GhostSea` at once, each with its own brand and token, and evaluates the body.
+///
+/// The invocation is `ghost_zip!((value, token) = &sea, (value, token) = &mut sea, ... => body)`, where each `sea` is
+/// borrowed immutably or mutably as indicated, and `value` and `token` are bound to its branded content and token, as
+/// with `GhostSea::apply_ref` and `GhostSea::apply_mut`. A `GhostDropSea` may be borrowed likewise.
+///
+/// See `GhostSea::zip_ref` and its siblings for the two instances case.
+///
+/// #   Examples
+///
+/// ```
+/// use ghost_sea::{ghost_zip, GhostCell, GhostSea};
+///
+/// let initials = GhostSea::new([GhostCell::new('A'), GhostCell::new('B')]);
+/// let ages = GhostSea::new([GhostCell::new(32u32), GhostCell::new(27)]);
+/// let mut oldest = GhostSea::new(GhostCell::new(' '));
+///
+/// ghost_zip!((initials, initials_token) = &initials, (ages, ages_token) = &ages, (oldest, token) = &mut oldest => {
+///     let (initial, _) = initials.iter()
+///         .zip(ages)
+///         .max_by_key(|(_, age)| *age.borrow(ages_token))
+///         .unwrap();
+///
+///     *oldest.borrow_mut(token) = *initial.borrow(initials_token);
+/// });
+///
+/// assert_eq!('A', oldest.apply_ref(|oldest, token| *oldest.borrow(token)));
+/// ```
+///
+/// The cells of one instance cannot be accessed with the token of another:
+///
+/// ```compile_fail
+/// use ghost_sea::{ghost_zip, GhostCell, GhostSea};
+///
+/// let one = GhostSea::new(GhostCell::new(1u32));
+/// let two = GhostSea::new(GhostCell::new(2u32));
+/// let three = GhostSea::new(GhostCell::new(3u32));
+///
+/// ghost_zip!((one, _) = &one, (_, _) = &two, (_, three_token) = &three => *one.borrow(three_token));
+/// ```
+#[macro_export]
+macro_rules! ghost_zip {
+    (($value:pat, $token:pat) = &mut $sea:expr => $body:expr) => {
+        (&mut $sea).apply_mut(|$value, $token| $body)
+    };
+    (($value:pat, $token:pat) = &mut $sea:expr, $($rest:tt)+) => {
+        (&mut $sea).apply_mut(|$value, $token| $crate::ghost_zip!($($rest)+))
+    };
+    (($value:pat, $token:pat) = &$sea:expr => $body:expr) => {
+        (&$sea).apply_ref(|$value, $token| $body)
+    };
+    (($value:pat, $token:pat) = &$sea:expr, $($rest:tt)+) => {
+        (&$sea).apply_ref(|$value, $token| $crate::ghost_zip!($($rest)+))
+    };
+}